@@ -0,0 +1,91 @@
+//! Flagging readings that stray from the trailing moving average, the average
+//! of the readings just before them, like the three-reading windows part 2
+//! sums.
+
+use std::fmt::Display;
+
+use crate::Reading;
+
+
+/// How far a reading has to be from its expected value to be flagged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    /// More than this far, in metres.
+    Absolute(f64),
+    /// More than this many standard deviations of the window.
+    Deviations(f64),
+}
+
+impl Display for Threshold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Threshold::Absolute(distance) => write!(f, "{}", distance),
+            Threshold::Deviations(sigmas) => write!(f, "{} standard deviations", sigmas),
+        }
+    }
+}
+
+/// A reading that strayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    pub line: usize,
+    pub depth: i64,
+    /// The average of the window of readings before this one.
+    pub expected: f64,
+    /// How much deeper the reading is than expected, negative if shallower.
+    pub deviation: f64,
+}
+
+
+/// Every reading after the first `window` that deviates from the average of
+/// the `window` readings before it by more than `threshold`. A window that
+/// doesn't vary at all flags any change from it.
+pub fn anomalies(readings: &[Reading], window: usize, threshold: Threshold) -> Vec<Anomaly> {
+    if window == 0 {
+        return Vec::new()
+    }
+
+    readings.windows(window + 1)
+        .filter_map(|readings| {
+            let (before, reading) = readings.split_at(window);
+            let reading = reading[0];
+
+            let expected = before.iter().map(|r| r.depth as f64).sum::<f64>() / window as f64;
+            let deviation = reading.depth as f64 - expected;
+            let limit = match threshold {
+                Threshold::Absolute(distance) => distance,
+                Threshold::Deviations(sigmas) => {
+                    let variance = before.iter()
+                        .map(|r| (r.depth as f64 - expected).powi(2))
+                        .sum::<f64>() / window as f64;
+                    sigmas * variance.sqrt()
+                }
+            };
+
+            (deviation.abs() > limit)
+                .then_some(Anomaly { line: reading.line, depth: reading.depth, expected, deviation })
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_log;
+
+    use super::*;
+
+    #[test]
+    fn strays_from_the_moving_average_are_flagged() {
+        let log = parse_log("100\n102\n101\n# spike\n140\n103\n102\n104\n103\n");
+
+        let flagged = anomalies(&log.readings, 3, Threshold::Absolute(20.0));
+        assert_eq!(flagged, [Anomaly { line: 5, depth: 140, expected: 101.0, deviation: 39.0 }]);
+
+        // the spike drags the next few averages up, but it widens their
+        // spread just as much, so the readings after it aren't flagged
+        let flagged = anomalies(&log.readings, 3, Threshold::Deviations(2.0));
+        let lines: Vec<_> = flagged.iter().map(|a| a.line).collect();
+        assert_eq!(lines, [5]);
+    }
+}
@@ -0,0 +1,19 @@
+//! Random sonar sweeps for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 2000;
+
+/// `size` depth readings, one per line, drifting deeper with some noise.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100..200) as i64;
+
+    (0..size)
+        .map(|_| {
+            depth = (depth + rng.range(0..31) as i64 - 10).max(0);
+            depth.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
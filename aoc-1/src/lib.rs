@@ -0,0 +1,214 @@
+//! Day 1: Sonar Sweep. Counts how often a series of depth readings increases,
+//! on its own and summed over a sliding window of three, or of any length
+//! with [`count_increases`].
+
+use std::{fmt::Display, num::ParseIntError};
+
+use aoc_common::{Location, Solution};
+
+pub mod anomaly;
+pub mod generate;
+pub mod report;
+pub mod stream;
+
+
+/// Why a depth log couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't a whole number of metres.
+    InvalidDepth { location: Location, found: String, source: ParseIntError },
+    /// A line that couldn't be read from the input at all.
+    Unreadable { line: usize, message: String },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidDepth { location, found, source } =>
+                write!(f, "{}: invalid depth {:?}: {}", location, found, source),
+            Error::Unreadable { line, message } => write!(f, "line {}: could not read: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+
+/// A depth reading and the input line it's on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reading {
+    pub line: usize,
+    pub depth: i64,
+}
+
+/// Everything in a depth log: its readings, and every line that should have
+/// been a reading but wasn't. What to do about those is up to the caller.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DepthLog {
+    pub readings: Vec<Reading>,
+    pub malformed: Vec<Error>,
+}
+
+impl DepthLog {
+    pub fn depths(&self) -> Vec<i64> {
+        self.readings.iter().map(|r| r.depth).collect()
+    }
+}
+
+/// Reads one signed depth per line, skipping blank lines and `#` comments,
+/// and carrying on past lines that aren't readings.
+pub fn parse_log(input: &str) -> DepthLog {
+    let mut log = DepthLog::default();
+    for (i, text) in input.lines().enumerate() {
+        match parse_line(i + 1, text) {
+            Some(Ok(depth)) => log.readings.push(Reading { line: i + 1, depth }),
+            Some(Err(err)) => log.malformed.push(err),
+            None => {}
+        }
+    }
+    log
+}
+
+/// Parses one depth reading per line like [`parse_log`], but fails on the
+/// first line that isn't one.
+pub fn parse_input(input: &str) -> Result<Vec<i64>, Error> {
+    let log = parse_log(input);
+    match log.malformed.into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(log.readings.into_iter().map(|r| r.depth).collect()),
+    }
+}
+
+/// The reading on line `line`, or `None` if it's blank or a comment.
+fn parse_line(line: usize, text: &str) -> Option<Result<i64, Error>> {
+    let reading = text.trim();
+    if reading.is_empty() || reading.starts_with('#') {
+        return None
+    }
+
+    let column = text.len() - text.trim_start().len() + 1;
+    Some(reading.parse::<i64>().map_err(|source| Error::InvalidDepth {
+        location: Location::new(line, column),
+        found: reading.into(),
+        source,
+    }))
+}
+
+
+/// How many `window`-reading windows sum deeper than the window before.
+///
+/// Two neighbouring windows share all but their ends, so the later one is
+/// deeper exactly when the reading it gains, `depths[i + window]`, is deeper
+/// than the one it loses, `depths[i]`. That takes no sums, so a window of a
+/// thousand readings costs no more than a window of one, and nothing can
+/// overflow. Empty windows never increase.
+pub fn count_increases(depths: &[i64], window: usize) -> usize {
+    depths.iter()
+        .zip(depths.iter().skip(window))
+        .filter(|(a, b)| b > a)
+        .count()
+}
+
+
+/// How many readings are deeper than the one before.
+pub fn problem_1(depths: &[i64]) -> usize {
+    count_increases(depths, 1)
+}
+
+
+/// How many three-reading windows sum deeper than the window before.
+pub fn problem_2(depths: &[i64]) -> usize {
+    count_increases(depths, 3)
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i64>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_2(input))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let depths = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(&depths), 7);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let depths = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(&depths), 5);
+    }
+
+    #[test]
+    fn windows_of_any_size_match_summing_them() {
+        let depths = parse_input(&generate::generate(&mut Rng::new(2), 500)).unwrap();
+
+        for window in [1, 2, 3, 10, 100, 499, 500, 501] {
+            let sums: Vec<i64> = depths.windows(window)
+                .map(|w| w.iter().sum())
+                .collect();
+            let summed = sums.windows(2).filter(|pair| pair[1] > pair[0]).count();
+
+            assert_eq!(count_increases(&depths, window), summed, "window of {}", window);
+        }
+    }
+
+    #[test]
+    fn reports_bad_depth() {
+        let err = parse_input("199\n2x0\n208").unwrap_err();
+        assert!(matches!(err, Error::InvalidDepth { location: Location { line: 2, column: 1 }, .. }));
+    }
+
+    #[test]
+    fn malformed_lines_are_collected_rather_than_fatal() {
+        let log = parse_log("# start of dive\n100\n\n-3\n  12x\n4294967296\n99999999999999999999\n");
+
+        assert_eq!(log.readings, [
+            Reading { line: 2, depth: 100 },
+            Reading { line: 4, depth: -3 },
+            Reading { line: 6, depth: 4294967296 },
+        ]);
+        let locations: Vec<_> = log.malformed.iter()
+            .map(|err| match err {
+                Error::InvalidDepth { location, .. } => *location,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(locations, [Location::new(5, 3), Location::new(7, 1)]);
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        assert!(parse_input(&input).is_ok());
+    }
+}
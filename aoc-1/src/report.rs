@@ -0,0 +1,133 @@
+//! A closer look at a depth series than the two puzzle answers: its longest
+//! rise, its largest drop, how big the steps between readings are and where
+//! it goes deeper.
+
+use std::{collections::BTreeMap, num::NonZeroU32};
+
+use itertools::Itertools;
+
+use crate::Reading;
+
+
+/// A stretch of readings each deeper than the one before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rise {
+    /// The input lines of the first and last readings.
+    pub start: usize,
+    pub end: usize,
+    pub readings: usize,
+}
+
+/// One reading that's shallower than the one before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fall {
+    /// The input line of the shallower reading.
+    pub line: usize,
+    pub from: i64,
+    pub to: i64,
+}
+
+impl Fall {
+    pub fn size(&self) -> u64 {
+        self.from.abs_diff(self.to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub readings: usize,
+    /// The longest rise, the first one if there's a tie.
+    pub longest_rise: Option<Rise>,
+    /// The largest drop, the first one if there's a tie.
+    pub largest_drop: Option<Fall>,
+    /// The width of each histogram bucket.
+    pub bucket: NonZeroU32,
+    /// How many steps from one reading to the next fall in each bucket, keyed
+    /// by the smallest change in it. Changes too big for an `i64` are counted
+    /// in the first or last bucket that fits.
+    pub histogram: BTreeMap<i64, usize>,
+    /// The input lines of the readings deeper than the one before.
+    pub increases: Vec<usize>,
+}
+
+
+/// Reports on `readings`, with the changes between them counted in buckets
+/// `bucket` wide.
+pub fn report(readings: &[Reading], bucket: NonZeroU32) -> Report {
+    // each step from one reading to the next, with the line it ends on
+    let steps: Vec<(usize, i64, i64)> = readings.iter()
+        .tuple_windows()
+        .map(|(a, b)| (b.line, a.depth, b.depth))
+        .collect();
+
+    let mut longest_rise = readings.first()
+        .map(|first| Rise { start: first.line, end: first.line, readings: 1 });
+    let mut current = longest_rise;
+    for &(line, a, b) in &steps {
+        current = match current {
+            Some(rise) if b > a => Some(Rise { end: line, readings: rise.readings + 1, ..rise }),
+            _ => Some(Rise { start: line, end: line, readings: 1 }),
+        };
+        if current.map(|r| r.readings) > longest_rise.map(|r| r.readings) {
+            longest_rise = current;
+        }
+    }
+
+    let largest_drop = steps.iter()
+        .filter(|(_, a, b)| b < a)
+        .map(|&(line, from, to)| Fall { line, from, to })
+        // max_by_key keeps the last of equals, so go backwards for the first
+        .rev()
+        .max_by_key(Fall::size);
+
+    let histogram = steps.iter()
+        .map(|&(_, a, b)| {
+            let bucket = bucket.get();
+            let start = (b as i128 - a as i128).div_euclid(bucket as i128) * bucket as i128;
+            start.clamp(i64::MIN as i128, i64::MAX as i128 - (bucket as i128 - 1)) as i64
+        })
+        .counts()
+        .into_iter()
+        .collect();
+
+    let increases = steps.iter()
+        .filter(|(_, a, b)| b > a)
+        .map(|&(line, _, _)| line)
+        .collect();
+
+    Report { readings: readings.len(), longest_rise, largest_drop, bucket, histogram, increases }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_log, problem_1};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_report() {
+        let log = parse_log(EXAMPLE);
+        let report = report(&log.readings, NonZeroU32::new(10).unwrap());
+
+        assert_eq!(report.readings, 10);
+        assert_eq!(report.longest_rise, Some(Rise { start: 1, end: 4, readings: 4 }));
+        assert_eq!(report.largest_drop, Some(Fall { line: 5, from: 210, to: 200 }));
+        assert_eq!(report.histogram, BTreeMap::from([(-10, 2), (0, 5), (20, 1), (30, 1)]));
+        assert_eq!(report.increases, [2, 3, 4, 6, 7, 8, 10]);
+        assert_eq!(report.increases.len(), problem_1(&log.depths()));
+    }
+
+    #[test]
+    fn lines_skipped_in_the_log_keep_their_numbers() {
+        let log = parse_log("# dive 2\n5\n\n7\n# surfacing\n-9223372036854775808\n9223372036854775807\n");
+        let report = report(&log.readings, NonZeroU32::MIN);
+
+        assert_eq!(report.longest_rise, Some(Rise { start: 2, end: 4, readings: 2 }));
+        assert_eq!(report.largest_drop, Some(Fall { line: 6, from: 7, to: i64::MIN }));
+        assert_eq!(report.increases, [4, 7]);
+        assert_eq!(report.histogram, BTreeMap::from([(i64::MIN, 1), (2, 1), (i64::MAX, 1)]));
+    }
+}
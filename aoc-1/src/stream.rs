@@ -0,0 +1,123 @@
+//! Counting increases while the readings are still coming in, for depth logs
+//! too long to hold in memory. Only the last `window` readings are kept.
+
+use std::{collections::VecDeque, fmt::Display, io::BufRead};
+
+use crate::{parse_line, Error};
+
+
+/// Increases counted so far for each window length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totals {
+    pub readings: usize,
+    /// The window lengths in the order asked for, each with its count.
+    pub increases: Vec<(usize, usize)>,
+}
+
+impl Display for Totals {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} readings", self.readings)?;
+        for (window, count) in &self.increases {
+            write!(f, ", window {}: {}", window, count)?;
+        }
+        Ok(())
+    }
+}
+
+
+/// Counts increases for several window lengths at once, a reading at a
+/// time, using the same comparison as [`count_increases`](crate::count_increases).
+pub struct Increases {
+    /// The last readings, as many as the longest window.
+    recent: VecDeque<i64>,
+    longest: usize,
+    totals: Totals,
+}
+
+impl Increases {
+    pub fn new(windows: &[usize]) -> Self {
+        let longest = windows.iter().copied().max().unwrap_or_default();
+        Increases {
+            recent: VecDeque::with_capacity(longest + 1),
+            longest,
+            totals: Totals { readings: 0, increases: windows.iter().map(|&w| (w, 0)).collect() },
+        }
+    }
+
+    pub fn push(&mut self, depth: i64) {
+        for (window, count) in self.totals.increases.iter_mut() {
+            let Some(index) = self.recent.len().checked_sub(*window) else { continue };
+            if *window > 0 && depth > self.recent[index] {
+                *count += 1;
+            }
+        }
+
+        self.recent.push_back(depth);
+        if self.recent.len() > self.longest {
+            self.recent.pop_front();
+        }
+        self.totals.readings += 1;
+    }
+
+    pub fn totals(&self) -> &Totals {
+        &self.totals
+    }
+}
+
+
+/// Counts increases for each of `windows` over one reading per line of
+/// `reader`, skipping blank lines and `#` comments like
+/// [`parse_log`](crate::parse_log). Every `report_every` readings, `report`
+/// is given the totals so far; 0 means never. Stops at the first line that
+/// can't be read or parsed.
+pub fn scan(
+    mut reader: impl BufRead,
+    windows: &[usize],
+    report_every: usize,
+    mut report: impl FnMut(&Totals),
+) -> Result<Totals, Error> {
+    let mut increases = Increases::new(windows);
+    let mut line = String::new();
+
+    for number in 1.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => return Err(Error::Unreadable { line: number, message: err.to_string() }),
+        }
+
+        let Some(depth) = parse_line(number, &line) else { continue };
+        increases.push(depth?);
+        if report_every > 0 && increases.totals.readings.is_multiple_of(report_every) {
+            report(increases.totals());
+        }
+    }
+
+    Ok(increases.totals)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use crate::{count_increases, generate, parse_input};
+
+    use super::*;
+
+    #[test]
+    fn streamed_counts_match_counting_all_at_once() {
+        let input = generate::generate(&mut Rng::new(3), 1000);
+        let depths = parse_input(&input).unwrap();
+        let windows = [1, 3, 10, 100, 0];
+
+        let mut reports = Vec::new();
+        let totals = scan(input.as_bytes(), &windows, 300, |t| reports.push(t.readings)).unwrap();
+
+        assert_eq!(reports, [300, 600, 900]);
+        assert_eq!(totals.readings, 1000);
+        let expected: Vec<_> = windows.iter().map(|&w| (w, count_increases(&depths, w))).collect();
+        assert_eq!(totals.increases, expected);
+    }
+}
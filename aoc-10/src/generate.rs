@@ -0,0 +1,64 @@
+//! Random navigation subsystems for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// The most brackets a line leaves open. Each one multiplies the completion
+/// score by 5, so any more and it could overflow.
+const MAX_OPEN: usize = 20;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// `size` lines of brackets, each either incomplete or corrupted by one wrong
+/// closing bracket. There's always an odd number of incomplete lines (and at
+/// least one), so part 2 has a middle score, and no line leaves more than 20
+/// brackets open.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut incomplete = 0;
+
+    (0..size)
+        .map(|i| {
+            let last = i + 1 == size;
+            let corrupt = if last { incomplete % 2 == 1 } else { rng.chance(1, 2) };
+            let line = line(rng, corrupt);
+            if !corrupt { incomplete += 1 }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn line(rng: &mut Rng, corrupt: bool) -> String {
+    let length = rng.range(20..100);
+    let mut open = Vec::new();
+    let mut line = String::new();
+
+    for _ in 0..length {
+        match open.last() {
+            Some(&close) if open.len() >= MAX_OPEN || rng.chance(2, 5) => {
+                line.push(close);
+                open.pop();
+            }
+            _ => {
+                let (opening, closing) = *rng.choose(&PAIRS);
+                line.push(opening);
+                open.push(closing);
+            }
+        }
+    }
+
+    // always leave something open so the line isn't complete
+    let (opening, closing) = *rng.choose(&PAIRS);
+    line.push(opening);
+    open.push(closing);
+
+    if corrupt {
+        let expected = *open.last().expect("a bracket was just opened");
+        let wrong: Vec<char> = PAIRS.iter().map(|&(_, c)| c).filter(|&c| c != expected).collect();
+        line.push(*rng.choose(&wrong));
+    }
+
+    line
+}
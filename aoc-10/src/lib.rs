@@ -0,0 +1,248 @@
+//! Day 10: Syntax Scoring. Sorts lines of brackets into corrupted ones, scored
+//! by their first bad bracket, and incomplete ones, scored by what closes them.
+
+use std::{collections::{VecDeque, HashMap}, fmt::Display};
+
+use aoc_common::{checked::{self, Overflow}, Location, Solution};
+use itertools::Itertools;
+
+pub mod generate;
+
+
+/// Why the navigation subsystem couldn't be parsed or scored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A character that isn't a bracket.
+    InvalidChar { location: Location, found: char },
+    /// No line is incomplete, so there's no middle score.
+    NoIncompleteLines,
+    /// A score got too big.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidChar { location, found } =>
+                write!(f, "{}: {:?} is not a bracket", location, found),
+            Error::NoIncompleteLines => write!(f, "no lines are incomplete"),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+
+/// A kind of bracket, named after its opening half.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    Paren,
+    Square,
+    Curly,
+    Arrow,
+}
+
+impl Symbol {
+    /// The closing bracket that matches this one.
+    pub fn get_reciprocal(&self) -> char {
+        match self {
+            Symbol::Paren => ')',
+            Symbol::Square => ']',
+            Symbol::Curly => '}',
+            Symbol::Arrow => '>',
+        }
+    }
+
+    pub fn is_reciprocal(&self, test: &char) -> bool {
+        self.get_reciprocal() == *test
+    }
+}
+
+impl TryFrom<char> for Symbol {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '(' => Ok(Symbol::Paren),
+            '[' => Ok(Symbol::Square),
+            '{' => Ok(Symbol::Curly),
+            '<' => Ok(Symbol::Arrow),
+            _ => Err("Invalid char")
+        }
+    }
+}
+
+/// How a line of brackets turned out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Line {
+    /// The first closing bracket that didn't match what was open.
+    Corrupted(char),
+    /// The brackets still open at the end, innermost first.
+    Incomplete(VecDeque<Symbol>),
+}
+
+/// Matches up the brackets on one line. Expects only bracket characters.
+pub fn parse_line(input: &str) -> Line {
+    let mut symbols: VecDeque<Symbol> = VecDeque::new();
+
+    for char in input.chars() {
+        if let Ok(symbol) = Symbol::try_from(char) {
+            symbols.push_front(symbol);
+        } else if let Some(symbol) = symbols.front() {
+            if symbol.is_reciprocal(&char) { 
+                symbols.pop_front(); 
+            } else {
+                return Line::Corrupted(char);
+            }
+        } else {
+            return Line::Corrupted(char);
+        }
+    }
+
+    Line::Incomplete(symbols)
+}
+
+fn check_brackets(input: &str) -> Result<(), Error> {
+    for (line, text) in input.lines().enumerate() {
+        if let Some((column, c)) = text.chars()
+            .enumerate()
+            .find(|(_, c)| !"()[]{}<>".contains(*c)) {
+            return Err(Error::InvalidChar { location: Location::new(line + 1, column + 1), found: c })
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks every line, rejecting anything that isn't a bracket.
+pub fn parse_input(input: &str) -> Result<Vec<Line>, Error> {
+    check_brackets(input)?;
+
+    Ok(input.lines().map(parse_line).collect())
+}
+
+/// The total syntax error score of the corrupted lines. Each count is at most
+/// the number of lines, so the scores can't overflow a `u64`.
+pub fn problem_1(lines: &[Line]) -> u64 {
+    let scores = lines.iter()
+        .filter_map(|line| match line {
+            Line::Corrupted(c) => Some(*c),
+            Line::Incomplete(_) => None,
+        })
+        .fold(HashMap::<char, u64>::new(), |mut acc, c| {
+            *acc.entry(c).or_insert(0) += 1;
+            acc
+        });
+
+    let paren = scores.get(&')').unwrap_or(&0) * 3;
+    let square = scores.get(&']').unwrap_or(&0) * 57;
+    let curly = scores.get(&'}').unwrap_or(&0) * 1197;
+    let arrow = scores.get(&'>').unwrap_or(&0) * 25137;
+
+    paren + square + curly + arrow
+}
+
+/// The score for closing the brackets still open on line `line`, which grows
+/// fivefold with each one.
+fn completion_score(line: usize, open: &VecDeque<Symbol>) -> Result<u64, Overflow> {
+    open.iter().try_fold(0, |acc: u64, s| {
+        let value = match s {
+            Symbol::Paren => 1,
+            Symbol::Square => 2,
+            Symbol::Curly => 3,
+            Symbol::Arrow => 4,
+        };
+        checked::add(checked::mul(acc, 5).at("score * 5", line)?, value).at("score + bracket", line)
+    })
+}
+
+/// The middle completion score of the incomplete lines.
+pub fn problem_2(lines: &[Line]) -> Result<u64, Error> {
+    let sorted_scores: Vec<u64> = lines.iter()
+        .enumerate()
+        .filter_map(|(i, line)| match line {
+            Line::Incomplete(open) => Some(completion_score(i + 1, open)),
+            Line::Corrupted(_) => None,
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .sorted()
+        .collect();
+
+    if sorted_scores.is_empty() {
+        return Err(Error::NoIncompleteLines)
+    }
+
+    let middle_index = (sorted_scores.len() as f32 / 2.0).floor() as usize;
+
+    Ok(sorted_scores[middle_index])
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Line>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let lines = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(&lines), 26397);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let lines = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(&lines).unwrap(), 288957);
+    }
+
+    #[test]
+    fn reports_stray_character() {
+        let err = parse_input("[({(<(())[]>[[{[]{<()<>>\n[(()[<>]x)]").unwrap_err();
+        assert_eq!(err, Error::InvalidChar { location: Location::new(2, 9), found: 'x' });
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        let lines = parse_input(&input).unwrap();
+
+        assert!(problem_2(&lines).is_ok());
+    }
+}
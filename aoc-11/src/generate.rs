@@ -0,0 +1,60 @@
+//! Random octopus grids for stress testing.
+
+use aoc_common::rng::Rng;
+use aoc_grid::{FlatGrid, Neighborhood};
+
+use crate::{Octopus, Step, MAX_STEPS};
+
+
+pub const DEFAULT_SIZE: usize = 10;
+
+/// How many cells' worth of random grids are tried before falling back to a
+/// built one: ten at the default size, none past about 30x30.
+const ATTEMPT_CELLS: usize = 1000;
+
+/// A `size` by `size` grid of energy levels that all flash together within
+/// 1000 steps. `size` must be at least 1.
+///
+/// Plenty of random grids never synchronise, and they get rarer as the grid
+/// grows, so after a few random grids that don't this builds one that does.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let levels = (0..ATTEMPT_CELLS / (size * size))
+        .map(|_| (0..size * size).map(|_| rng.range(0..10) as u8).collect::<Vec<_>>())
+        .find(|levels| synchronises(levels, size))
+        .unwrap_or_else(|| synchronised(rng, size));
+
+    levels.chunks(size)
+        .map(|row| row.iter().map(|l| char::from(b'0' + l)).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn synchronises(levels: &[u8], size: usize) -> bool {
+    let mut grid = FlatGrid::new(levels.iter().map(|&l| Octopus::from(l)).collect(), size, size)
+        .with_neighborhood(Neighborhood::Moore);
+
+    (0..MAX_STEPS).any(|_| grid.step() == grid.len())
+}
+
+/// Levels that all flash together on one of the first few steps.
+///
+/// Taking the cells row by row, each one is charged enough that the flashes
+/// of the neighbours before it (left, and the three above) tip it over, and
+/// the first is a 9. Everything is then lowered by the same amount, which
+/// only delays that cascade, as nothing can flash before it.
+fn synchronised(rng: &mut Rng, size: usize) -> Vec<u8> {
+    let charged: Vec<u8> = (0..size * size)
+        .map(|i| {
+            let (x, y) = (i % size, i / size);
+            let before = [x > 0, x > 0 && y > 0, y > 0, x + 1 < size && y > 0]
+                .into_iter()
+                .filter(|&b| b)
+                .count() as u64;
+            rng.range(9 - before..10) as u8
+        })
+        .collect();
+
+    let lowest = charged.iter().copied().min().unwrap_or_default();
+    let lower = rng.range(0..lowest as u64 + 1) as u8;
+    charged.into_iter().map(|l| l - lower).collect()
+}
@@ -0,0 +1,236 @@
+//! Day 11: Dumbo Octopus. Steps a grid of octopuses whose flashes set off their
+//! neighbours, counting flashes until they all flash at once.
+
+use std::fmt::Display;
+
+use aoc_common::Solution;
+use aoc_grid::{FlatGrid, Neighborhood, ParseError};
+use itertools::Itertools;
+
+pub mod generate;
+
+
+/// How many steps part 2 waits for the octopuses to synchronise.
+pub const MAX_STEPS: usize = 1000;
+
+
+/// Why the octopuses couldn't be parsed or synchronised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The energy levels aren't a rectangle of digits.
+    Grid(ParseError),
+    /// They didn't all flash at once within `steps` steps.
+    NeverSynchronises { steps: usize },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Grid(err) => write!(f, "{}", err),
+            Error::NeverSynchronises { steps } =>
+                write!(f, "octopuses didn't all flash together within {} steps", steps),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Grid(err)
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum OctopusState {
+    Building(u8),
+    Flashing,
+}
+
+impl Display for OctopusState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match *self {
+            Self::Building(n) => n.to_string(),
+            Self::Flashing => "#".to_string(),
+        })
+    }
+}
+
+/// One octopus, building up energy until it flashes.
+#[derive(Debug, Clone)]
+pub struct Octopus(OctopusState);
+
+impl Display for Octopus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Octopus {
+    fn incr(&mut self) -> usize {
+        match self.0 {
+            OctopusState::Building(n) if n < 9 => {
+                self.0 = OctopusState::Building(n + 1);
+                0
+            },
+            OctopusState::Building(_) => {
+                self.0 = OctopusState::Flashing;
+                1
+            }
+            _ => 0
+        }
+    }
+
+    fn reset(&mut self) {
+        self.0 = if self.0 == OctopusState::Flashing {
+            OctopusState::Building(0)
+        } else { 
+            self.0
+        }
+    }
+}
+
+/// An octopus with the given energy level. Anything above 9 is flashing.
+impl From<u8> for Octopus {
+    fn from(input: u8) -> Self {
+        if input < 10 { Octopus(OctopusState::Building(input)) }
+        else { Octopus(OctopusState::Flashing) }
+    }
+}
+
+pub trait Step {
+    /// Raises every octopus's energy, lets the flashes spread, and returns
+    /// how many flashed.
+    fn step(&mut self) -> usize;
+}
+
+impl Step for FlatGrid<Octopus> {
+    fn step(&mut self) -> usize {
+        let mut total_flashes = self.iter_mut()
+            .map(|o| o.incr())
+            .sum();
+        let mut flashing: Vec<usize> = self.iter()
+            .enumerate()
+            .filter(|(_, o)| o.0 == OctopusState::Flashing)
+            .map(|(i, _)| i)
+            .collect();
+
+        while !flashing.is_empty() {
+            let neighbors: Vec<usize> = flashing.iter()
+                .flat_map(|n| self.get_neighbor_positions(*n))
+                .filter(|&n| self.get(n).unwrap().0 != OctopusState::Flashing)
+                .collect();
+            
+            total_flashes += neighbors.iter()
+                .map(|n| self.get_mut(*n).unwrap().incr())
+                .sum::<usize>();
+
+            flashing = neighbors.iter()
+                .filter(|&&n| self.get(n).unwrap().0 == OctopusState::Flashing)
+                .cloned()
+                .unique()
+                .collect();
+        }
+
+        for o in self.iter_mut() {
+            o.reset();
+        }
+
+        total_flashes
+    }
+}
+
+
+/// Parses the grid of energy levels, with each octopus touching all eight
+/// cells around it.
+pub fn parse_input(input: &str) -> Result<FlatGrid<Octopus>, Error> {
+    Ok(FlatGrid::from_digit_str(input)?
+        .with_neighborhood(Neighborhood::Moore))
+}
+
+/// How many flashes there are over 100 steps.
+pub fn problem_1(mut input: FlatGrid<Octopus>) -> usize {
+    (0..100).map(|_| input.step()).sum()
+}
+
+/// The first step on which every octopus flashes, giving up after
+/// [`MAX_STEPS`].
+pub fn problem_2(mut input: FlatGrid<Octopus>) -> Result<usize, Error> {
+    let flash_num = input.len();
+
+    (1..=MAX_STEPS)
+        .find(|_| input.step() == flash_num)
+        .ok_or(Error::NeverSynchronises { steps: MAX_STEPS })
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 11;
+
+    type Input<'a> = FlatGrid<Octopus>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_1(input.clone()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        assert_eq!(problem_1(parse_input(EXAMPLE).unwrap()), 1656);
+    }
+
+    #[test]
+    fn example_part_2() {
+        assert_eq!(problem_2(parse_input(EXAMPLE).unwrap()), Ok(195));
+    }
+
+    #[test]
+    fn reports_grid_that_never_synchronises() {
+        let grid = parse_input("6618\n5249\n2032\n8921").unwrap();
+        assert_eq!(problem_2(grid), Err(Error::NeverSynchronises { steps: MAX_STEPS }));
+    }
+
+    #[test]
+    fn reports_ragged_row() {
+        let err = parse_input("5483\n274\n5264").unwrap_err();
+        assert_eq!(err, Error::Grid(ParseError::Ragged { line: 2, expected: 4, found: 3 }));
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        let grid = parse_input(&input).unwrap();
+        assert!(problem_2(grid).is_ok());
+    }
+
+    #[test]
+    fn large_generated_input_solves() {
+        for seed in 0..5 {
+            let input = generate::generate(&mut Rng::new(seed), 50);
+            assert!(problem_2(parse_input(&input).unwrap()).is_ok());
+        }
+    }
+}
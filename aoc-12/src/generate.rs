@@ -0,0 +1,44 @@
+//! Random cave systems for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 30;
+
+/// Cave names skip `e` and `s` so they can never spell `end` or `start`.
+const LETTERS: &[u8] = b"abcdfghijklmnopqrtuvwxyz";
+
+fn name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(LETTERS[index % LETTERS.len()]);
+        index /= LETTERS.len();
+        if index == 0 && name.len() >= 2 { break }
+    }
+    String::from_utf8(name).expect("names are ascii")
+}
+
+/// A chain of `size` small caves from `start` to `end`, with large caves
+/// bridging a third of the links at random. Large caves only touch small
+/// ones, and each bridge doubles the number of routes, so part 1 has exactly
+/// `2^(size / 3)` paths. Part 2 has more, but grows at a similar rate.
+/// `size` must be at least 1.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let small: Vec<String> = (0..size).map(name).collect();
+    let mut links: Vec<usize> = (0..size - 1).collect();
+    rng.shuffle(&mut links);
+    let bridged = &links[..(size / 3).min(links.len())];
+
+    let mut edges = vec![format!("start-{}", small[0]), format!("{}-end", small[size - 1])];
+    for i in 0..size - 1 {
+        edges.push(format!("{}-{}", small[i], small[i + 1]));
+    }
+    for (n, &i) in bridged.iter().enumerate() {
+        let large = name(n).to_uppercase();
+        edges.push(format!("{}-{}", small[i], large));
+        edges.push(format!("{}-{}", large, small[i + 1]));
+    }
+
+    rng.shuffle(&mut edges);
+    edges.join("\n")
+}
@@ -0,0 +1,348 @@
+//! Day 12: Passage Pathing. Counts the routes through a cave system that visit
+//! small caves at most once, then with one small cave allowed twice.
+
+use std::{collections::HashMap, hash::Hash, fmt::Display};
+
+use aoc_common::{checked::{self, Overflow}, parse, Solution};
+use nom::{IResult, character::complete::alpha1};
+
+pub mod generate;
+pub mod reference;
+
+
+/// Why the caves couldn't be parsed or explored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't two cave names joined by `-`.
+    Parse(parse::Error),
+    /// Two large caves joined to each other, so paths could loop forever.
+    AdjacentLargeCaves { line: usize },
+    /// There's no way out of the `start` cave.
+    NoPath,
+    /// More small caves than a path can keep track of.
+    TooManySmallCaves { found: usize },
+    /// There are too many paths to count.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::AdjacentLargeCaves { line } =>
+                write!(f, "line {}: two large caves are connected, so there are infinitely many paths", line),
+            Error::NoPath => write!(f, "there's no way out of the start cave"),
+            Error::TooManySmallCaves { found } =>
+                write!(f, "{} small caves is more than the {} that can be tracked", found, MAX_SMALL_CAVES),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<parse::Error> for Error {
+    fn from(err: parse::Error) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+
+trait IsUppercase {
+    fn is_uppercase(&self) -> bool;
+}
+
+impl IsUppercase for String {
+    fn is_uppercase(&self) -> bool {
+        self.to_ascii_uppercase() == *self
+    }
+}
+
+
+/// A graph stored as each node's list of neighbours.
+#[derive(Debug, Clone)]
+pub struct Graph<T>(HashMap<T,Vec<T>>);
+
+impl<T> Graph<T>
+where
+    T: Hash + Eq + Clone
+{
+    pub fn new() -> Self {
+        Graph(HashMap::new())
+    }
+
+    pub fn add_one_way_relation(&mut self, start: T, end: T) {
+        self.0.entry(start).or_default().push(end);
+    }
+
+    /// Connects `a` and `b` both ways.
+    pub fn add_relation(&mut self, a: T, b: T) {
+        self.add_one_way_relation(a.clone(), b.clone());
+        self.add_one_way_relation(b, a);
+    }
+
+    /// The nodes `node` leads to, or `None` if it isn't in the graph.
+    pub fn get_relations(&self, node: &T) -> Option<&[T]> {
+        Some(&self.0.get(node)?[..])
+    }
+}
+
+impl<T> Default for Graph<T>
+where
+    T: Hash + Eq + Clone
+{
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+/// A cave. Large caves, named in capitals, can be visited any number of times.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Node {
+    Start,
+    End,
+    Large(String),
+    Small(String),
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Node::Start => "start".to_string(),
+            Node::End => "end".to_string(),
+            Node::Large(s) => format!("Large({})", s),
+            Node::Small(s) => format!("Small({})", s),
+        })
+    }
+}
+
+impl<T> From<Vec<(T, T)>> for Graph<T>
+where
+    T: Hash + Eq + Clone
+{
+    fn from(input: Vec<(T, T)>) -> Self {
+        let mut out = Graph::new();
+        for (a, b) in input {
+            out.add_relation(a, b);
+        }
+        out
+    }
+}
+
+
+/// How many small caves a path can keep track of.
+const MAX_SMALL_CAVES: usize = u128::BITS as usize;
+
+/// Small caves visited so far on a path, one bit each.
+type Visited = u128;
+
+impl Graph<Node> {
+    /// How many paths lead from `start` to `end`, visiting small caves at
+    /// most once, apart from a single one twice if `revisit` is set.
+    ///
+    /// Where a path can go next only depends on where it is, which small
+    /// caves it has seen and whether it has used its revisit, so the count
+    /// from each such state is only worked out once.
+    fn count_paths(&self, revisit: bool) -> Result<u64, Error> {
+        let exits = self.get_relations(&Node::Start).unwrap_or_default();
+        if exits.iter().all(|n| *n == Node::Start) {
+            return Err(Error::NoPath)
+        }
+
+        let small: HashMap<&Node, usize> = self.0.keys()
+            .filter(|n| matches!(n, Node::Small(_)))
+            .enumerate()
+            .map(|(i, n)| (n, i))
+            .collect();
+        if small.len() > MAX_SMALL_CAVES {
+            return Err(Error::TooManySmallCaves { found: small.len() })
+        }
+
+        let mut paths = Paths { graph: self, small, known: HashMap::new() };
+        Ok(paths.from(&Node::Start, 0, !revisit)?)
+    }
+}
+
+struct Paths<'a> {
+    graph: &'a Graph<Node>,
+    small: HashMap<&'a Node, usize>,
+    known: HashMap<(&'a Node, Visited, bool), u64>,
+}
+
+impl<'a> Paths<'a> {
+    fn from(&mut self, current: &'a Node, visited: Visited, revisited: bool) -> Result<u64, Overflow> {
+        if let Some(&count) = self.known.get(&(current, visited, revisited)) {
+            return Ok(count)
+        }
+
+        let mut count = 0;
+        for next in self.graph.get_relations(current).unwrap_or_default() {
+            let paths = match next {
+                Node::Start => 0,
+                Node::End => 1,
+                Node::Large(_) => self.from(next, visited, revisited)?,
+                Node::Small(_) => {
+                    let bit = 1 << self.small[next];
+                    if visited & bit == 0 {
+                        self.from(next, visited | bit, revisited)?
+                    } else if !revisited {
+                        self.from(next, visited, true)?
+                    } else {
+                        0
+                    }
+                }
+            };
+            count = checked::add(count, paths).on("path count")?;
+        }
+
+        self.known.insert((current, visited, revisited), count);
+        Ok(count)
+    }
+}
+
+
+/// Classifies a cave by its name.
+pub fn parse_node(input: &str) -> Node {
+    match input {
+        "start" => Node::Start,
+        "end" => Node::End,
+        n if n.to_string().is_uppercase() => Node::Large(n.into()),
+        n => Node::Small(n.into()),
+    }
+}
+
+fn parse_node_pair(input: &str) -> IResult<&str, (Node, Node)> {
+    let (out, (a, b)) = parse::separated(alpha1, "-", alpha1)(input)?;
+    Ok((out, (parse_node(a), parse_node(b))))
+}
+
+/// Parses one `<cave>-<cave>` passage per line. Two connected large caves
+/// would allow endless paths, so they're rejected.
+pub fn parse_input(input: &str) -> Result<Graph<Node>, Error> {
+    let pairs = parse::complete(input, parse::lines(parse_node_pair))?;
+
+    if let Some(i) = pairs.iter()
+        .position(|pair| matches!(pair, (Node::Large(_), Node::Large(_)))) {
+        return Err(Error::AdjacentLargeCaves { line: i + 1 })
+    }
+
+    Ok(pairs.into())
+}
+
+
+/// How many paths from `start` to `end` visit each small cave at most once.
+pub fn problem_1(input: Graph<Node>) -> Result<u64, Error> {
+    input.count_paths(false)
+}
+
+/// How many paths from `start` to `end` visit a single small cave at most
+/// twice and the rest at most once.
+pub fn problem_2(input: Graph<Node>) -> Result<u64, Error> {
+    input.count_paths(true)
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Graph<Node>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{differential, rng::Rng};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let input = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(input).unwrap(), 10);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let input = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(input).unwrap(), 36);
+    }
+
+    #[test]
+    fn reports_adjacent_large_caves() {
+        let err = parse_input("start-A\nA-B\nB-end").unwrap_err();
+        assert_eq!(err, Error::AdjacentLargeCaves { line: 2 });
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        let graph = parse_input(&input).unwrap();
+        assert_eq!(problem_1(graph.clone()).unwrap(), 1 << (generate::DEFAULT_SIZE / 3));
+        assert!(problem_2(graph).is_ok());
+    }
+
+    /// Up to `size` distinct passages between a handful of caves, never
+    /// joining two large ones.
+    fn random_passages(rng: &mut Rng, size: usize) -> Vec<(Node, Node)> {
+        let caves = ["start", "end", "a", "b", "c", "d", "f", "A", "B"].map(parse_node);
+
+        let mut passages: Vec<(Node, Node)> = Vec::new();
+        for _ in 0..size {
+            let pair = (rng.choose(&caves).clone(), rng.choose(&caves).clone());
+            let large = matches!(pair, (Node::Large(_), Node::Large(_)));
+            if !large && !passages.contains(&pair) {
+                passages.push(pair);
+            }
+        }
+        passages
+    }
+
+    #[test]
+    fn optimised_matches_reference() {
+        let config = differential::Config { max_size: 16, ..Default::default() };
+
+        for (reference, optimised) in [
+            (reference::problem_1 as fn(Graph<Node>) -> _, problem_1 as fn(Graph<Node>) -> _),
+            (reference::problem_2, problem_2),
+        ] {
+            let result = differential::check(
+                &config,
+                random_passages,
+                |passages| differential::shrink_vec(passages),
+                |passages| reference(passages.clone().into()),
+                |passages| optimised(passages.clone().into()),
+            );
+
+            if let Err(mismatch) = result {
+                panic!("{}", mismatch);
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Builds the full tree of paths and counts its leaves, kept to check the
+//! memoised count in the crate root against.
+
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::{Error, Graph, Node};
+
+
+enum Tree<T> {
+    Branch(Vec<Tree<T>>, T),
+    Leaf(T)
+}
+
+impl<T> Tree<T> {
+    fn count_leaves(&self) -> u64 {
+        match self {
+            Tree::Branch(children, _) => {
+                children.iter()
+                    .map(|c| c.count_leaves())
+                    .sum()
+            },
+            Tree::Leaf(_) => 1,
+        }
+    }
+}
+
+impl<T> Display for Tree<T>
+where
+    T: Display
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tree::Branch(children, n) => {
+                let children: String = children.iter()
+                    .map(|c| format!("{}\n", c))
+                    .collect();
+                let children: String = Itertools::intersperse(
+                    children.lines().map(|s| format!("  {}", s)),
+                    "\n".into()
+                ).collect();
+
+                write!(f, "{}\n{}", n, children)
+            }
+            Tree::Leaf(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+
+impl Graph<Node> {
+    fn to_tree(&self) -> Option<Tree<Node>> {
+        let mut visited = Vec::new();
+        self.to_tree_recursive(Node::Start, &mut visited)
+    }
+
+    fn to_tree_recursive(&self, current: Node, visited: &mut Vec<Node>) -> Option<Tree<Node>> {
+        if current == Node::End {
+            return Some(Tree::Leaf(current))
+        }
+
+        let connected = self.get_relations(&current)?;
+        let valid_connections: Vec<_> = connected.iter()
+            .filter(|&n| match n {
+                Node::Large(_) => true,
+                // `current` isn't in `visited` yet, so passages back to
+                // itself need ruling out separately
+                _ if *n == current => false,
+                _ => !visited.contains(n),
+            })
+            .collect();
+        
+        if valid_connections.is_empty() {
+            None
+        } else {
+            visited.push(current.clone());
+            let out = Some(Tree::Branch(valid_connections.iter()
+                .filter_map(|&n| self.to_tree_recursive(n.clone(), visited))
+                .collect(), current));
+            visited.pop();
+            out
+        }
+    }
+
+    fn to_tree_small_twice(&self) -> Option<Tree<Node>> {
+        let mut visited = Vec::new();
+        self.to_tree_small_twice_recursive(Node::Start, &mut visited, false)
+    }
+
+    fn to_tree_small_twice_recursive(
+        &self, 
+        current: Node, 
+        visited: &mut Vec<Node>, 
+        mut used_small: bool
+    ) -> Option<Tree<Node>> {
+        if current == Node::End {
+            return Some(Tree::Leaf(current))
+        }
+
+        if let Node::Small(_) = current {
+            if visited.contains(&current) {
+                used_small = true;
+            }
+        }
+
+        let connected = self.get_relations(&current)?;
+        let valid_connections: Vec<_> = connected.iter()
+            .filter(|&n| match n {
+                Node::Large(_) => true,
+                Node::Small(_) if !used_small => true,
+                _ if *n == current => false,
+                _ => !visited.contains(n)
+            })
+            .collect();
+        
+        if valid_connections.is_empty() {
+            None
+        } else {
+            visited.push(current.clone());
+            let out = Some(Tree::Branch(valid_connections.iter()
+                .filter_map(|&n| self.to_tree_small_twice_recursive(n.clone(), visited, used_small))
+                .collect(), current));
+            visited.pop();
+            out
+        }
+    }
+}
+
+
+pub fn problem_1(input: Graph<Node>) -> Result<u64, Error> {
+    let tree = input.to_tree().ok_or(Error::NoPath)?;
+    Ok(tree.count_leaves())
+}
+
+pub fn problem_2(input: Graph<Node>) -> Result<u64, Error> {
+    let tree = input.to_tree_small_twice().ok_or(Error::NoPath)?;
+    Ok(tree.count_leaves())
+}
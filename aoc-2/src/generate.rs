@@ -0,0 +1,25 @@
+//! Random submarine courses for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` commands, one per line. `up` never takes the aim (or the depth)
+/// above the surface.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut aim = 0;
+
+    (0..size)
+        .map(|_| {
+            let distance = rng.range(1..10);
+            let direction = match rng.range(0..3) {
+                0 => "forward",
+                1 if aim >= distance => { aim -= distance; "up" }
+                _ => { aim += distance; "down" }
+            };
+            format!("{} {}", direction, distance)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,208 @@
+//! Day 2: Dive! Follows the submarine's course, first reading `up` and `down`
+//! as depth changes and then as changes to its aim.
+
+use std::{fmt::Display, num::ParseIntError};
+
+use aoc_common::{checked::{self, Overflow}, Location, Solution};
+use itertools::Itertools;
+
+pub mod generate;
+
+
+/// Why the course couldn't be parsed or followed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A command with no distance after its direction.
+    MissingDistance { location: Location },
+    /// A distance that isn't a whole number.
+    InvalidDistance { location: Location, found: String, source: ParseIntError },
+    /// A direction other than `forward`, `down` or `up`.
+    InvalidDirection { location: Location, found: String },
+    /// The position, depth or aim overflowed.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingDistance { location } =>
+                write!(f, "{}: expected `<direction> <distance>`", location),
+            Error::InvalidDistance { location, found, source } =>
+                write!(f, "{}: invalid distance {:?}: {}", location, found, source),
+            Error::InvalidDirection { location, found } =>
+                write!(f, "{}: direction {:?} is not forward, down or up", location, found),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+
+/// One command from the course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+
+fn deserialize(line: usize, input: &str) -> Result<Direction, Error> {
+    let words = input.split(' ');
+    let (direction, distance) = words.collect_tuple()
+        .ok_or(Error::MissingDistance { location: Location::new(line, 1) })?;
+    let distance = distance.parse().map_err(|source| Error::InvalidDistance {
+        location: Location::new(line, direction.len() + 2),
+        found: distance.into(),
+        source,
+    })?;
+    match direction {
+        "forward" => Ok(Direction::Forward(distance)),
+        "down" => Ok(Direction::Down(distance)),
+        "up" => Ok(Direction::Up(distance)),
+        _ => Err(Error::InvalidDirection { location: Location::new(line, 1), found: direction.into() }),
+    }
+}
+
+/// Parses one `<direction> <distance>` command per line.
+pub fn parse_input(input: &str) -> Result<Vec<Direction>, Error> {
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| deserialize(i + 1, line))
+        .collect()
+}
+
+/// Where the submarine is. Depth grows downwards, and can go negative if the
+/// course takes the submarine above the surface. `aim` is only used in part 2.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+    pub aim: i64,
+}
+
+impl Position {
+    /// The horizontal position times the depth.
+    fn product(&self) -> Result<i64, Overflow> {
+        checked::mul(self.x, self.y).on("horizontal position * depth")
+    }
+}
+
+/// The horizontal position times the depth, with `up` and `down` moving the
+/// submarine directly.
+pub fn problem_1(directions: &[Direction]) -> Result<i64, Error> {
+    let mut position = Position::default();
+
+    for (i, direction) in directions.iter().enumerate() {
+        let line = i + 1;
+        match *direction {
+            Direction::Forward(distance) =>
+                position.x = checked::add(position.x, distance.into()).at("position + distance", line)?,
+            Direction::Down(distance) =>
+                position.y = checked::add(position.y, distance.into()).at("depth + distance", line)?,
+            Direction::Up(distance) =>
+                position.y = checked::sub(position.y, distance.into()).at("depth - distance", line)?,
+        }
+    }
+
+    Ok(position.product()?)
+}
+
+/// The horizontal position times the depth, with `up` and `down` changing the
+/// aim and `forward` diving along it.
+pub fn problem_2(directions: &[Direction]) -> Result<i64, Error> {
+    let mut position = Position::default();
+
+    for (i, direction) in directions.iter().enumerate() {
+        let line = i + 1;
+        match *direction {
+            Direction::Forward(distance) => {
+                let distance = distance.into();
+                position.x = checked::add(position.x, distance).at("position + distance", line)?;
+                let dive = checked::mul(position.aim, distance).at("aim * distance", line)?;
+                position.y = checked::add(position.y, dive).at("depth + aim * distance", line)?;
+            },
+            Direction::Down(distance) =>
+                position.aim = checked::add(position.aim, distance.into()).at("aim + distance", line)?,
+            Direction::Up(distance) =>
+                position.aim = checked::sub(position.aim, distance.into()).at("aim - distance", line)?,
+        }
+    }
+
+    Ok(position.product()?)
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Direction>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let directions = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(&directions).unwrap(), 150);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let directions = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(&directions).unwrap(), 900);
+    }
+
+    #[test]
+    fn reports_bad_direction() {
+        let err = parse_input("forward 5\nsideways 3").unwrap_err();
+        assert!(matches!(err, Error::InvalidDirection { location: Location { line: 2, column: 1 }, .. }));
+    }
+
+    #[test]
+    fn surfacing_gives_a_negative_depth() {
+        let directions = parse_input("forward 2\nup 3").unwrap();
+        assert_eq!(problem_1(&directions).unwrap(), -6);
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        let directions = parse_input(&input).unwrap();
+
+        assert!(problem_1(&directions).is_ok());
+        assert!(problem_2(&directions).is_ok());
+    }
+}
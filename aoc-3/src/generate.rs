@@ -0,0 +1,48 @@
+//! Random diagnostic reports for stress testing.
+
+use std::collections::HashSet;
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` distinct binary numbers, one per line. They're 12 bits wide, or
+/// wider if that's too few to keep them distinct.
+///
+/// Being distinct keeps the oxygen rating unique, but the CO2 filter can still
+/// empty out when every number left shares a bit. Wherever that would happen,
+/// one of them has that bit flipped, which leaves it as the only candidate.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = (usize::BITS - size.leading_zeros() + 1).max(12);
+    assert!(width <= 32, "too many numbers to keep them distinct");
+
+    let mut seen = HashSet::new();
+    let mut numbers = Vec::with_capacity(size);
+    while numbers.len() < size {
+        let n = rng.range(0..1 << width);
+        if seen.insert(n) {
+            numbers.push(n);
+        }
+    }
+
+    let mut remaining: Vec<usize> = (0..numbers.len()).collect();
+    for place in (0..width).rev() {
+        if remaining.len() < 2 { break }
+
+        let ones = remaining.iter().filter(|&&i| numbers[i] >> place & 1 == 1).count();
+        if ones == 0 || ones == remaining.len() {
+            // nothing else has this prefix, so the flip can't make a duplicate
+            numbers[remaining[0]] ^= 1 << place;
+        }
+
+        let ones = remaining.iter().filter(|&&i| numbers[i] >> place & 1 == 1).count();
+        let keep = u64::from(ones * 2 < remaining.len());
+        remaining.retain(|&i| numbers[i] >> place & 1 == keep);
+    }
+
+    numbers.iter()
+        .map(|n| format!("{:0width$b}", n, width = width as usize))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
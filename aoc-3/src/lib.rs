@@ -0,0 +1,264 @@
+//! Day 3: Binary Diagnostic. Reads the submarine's power consumption and
+//! life support ratings out of a report of binary numbers.
+
+use std::fmt::Display;
+
+use aoc_common::{trace::Tracer, Location, Solution};
+
+pub mod generate;
+
+
+const TRACE: Tracer = Tracer::new(3);
+
+
+/// Why the diagnostic report couldn't be parsed or rated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// There are no numbers at all.
+    Empty,
+    /// A character other than 0 or 1.
+    InvalidBit { location: Location, found: char },
+    /// A number with a different width to the first.
+    WrongWidth { line: usize, expected: usize, found: usize },
+    /// Numbers wider than the 32 bits they're stored in.
+    TooWide { found: usize },
+    /// A rating's filter didn't end on exactly one number.
+    NoUniqueRating { rating: &'static str, remaining: usize },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Empty => write!(f, "input has no numbers"),
+            Error::InvalidBit { location, found } =>
+                write!(f, "{}: expected 0 or 1, found {:?}", location, found),
+            Error::WrongWidth { line, expected, found } =>
+                write!(f, "line {}: expected {} bits, found {}", line, expected, found),
+            Error::TooWide { found } =>
+                write!(f, "numbers are {} bits wide, at most 32 are supported", found),
+            Error::NoUniqueRating { rating, remaining } =>
+                write!(f, "{} rating filter left {} numbers instead of 1", rating, remaining),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+
+/// The diagnostic report: numbers that are all `width` bits wide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub numbers: Vec<u32>,
+    pub width: usize,
+}
+
+/// Parses one binary number per line. Every line must be as wide as the first,
+/// and no wider than 32 bits.
+pub fn parse_input(input: &str) -> Result<Report, Error> {
+    let word_size = input.lines().next().map_or(0, str::len);
+    if word_size == 0 {
+        return Err(Error::Empty)
+    }
+    if word_size > 32 {
+        return Err(Error::TooWide { found: word_size })
+    }
+
+    let words = input.lines()
+        .enumerate()
+        .map(|(line, n)| {
+            if let Some((column, c)) = n.chars().enumerate().find(|(_, c)| !matches!(c, '0' | '1')) {
+                return Err(Error::InvalidBit { location: Location::new(line + 1, column + 1), found: c })
+            }
+            if n.len() != word_size {
+                return Err(Error::WrongWidth { line: line + 1, expected: word_size, found: n.len() })
+            }
+            Ok(u32::from_str_radix(n, 2).expect("digits were checked"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Report { numbers: words, width: word_size })
+}
+
+fn count_ones(words: &[u32], size: usize) -> Vec<u32> {
+    words.iter()
+        .fold(
+            vec![0; size],
+            |mut acc, &word| {
+                for (index, current) in acc.iter_mut().enumerate() {
+                    *current += ((word as usize) >> (size - index - 1) & 1) as u32
+                }
+                acc
+            }
+        )
+}
+
+fn get_rounded_distribution(ones: &[u32], total: u32) -> Vec<u32> {
+    ones.iter()
+        .map(|&n| total as i32 - n as i32 * 2)
+        .map(|n| if n <= 0 { 1 } else { 0 })
+        .collect()
+}
+
+fn bit_array_to_int(arr: &[u32]) -> u32 {
+    arr.iter()
+        .fold(0, |acc, n| acc << 1 | n & 1)
+}
+
+/// The gamma rate, made of each bit's most common value, and the epsilon
+/// rate, made of the least common.
+pub fn power_rates(report: &Report) -> (u32, u32) {
+    let word_size = report.width;
+    let word_num = report.numbers.len();
+
+    let ones = count_ones(&report.numbers, word_size);
+    let distr = get_rounded_distribution(&ones, word_num as u32);
+
+    let gamma = bit_array_to_int(&distr);
+    let epsilon = !gamma & (u32::MAX >> (32 - word_size));
+
+    (gamma, epsilon)
+}
+
+/// The power consumption: the gamma rate times the epsilon rate. Both rates
+/// fit in 32 bits, so their product always fits in a `u64`.
+pub fn problem_1(report: &Report) -> u64 {
+    let (gamma, epsilon) = power_rates(report);
+    gamma as u64 * epsilon as u64
+}
+
+fn most_common_bit(numbers: &[u32], place: u32) -> u32 {
+    let (total_zeros, total_ones) = numbers.iter().fold(
+        (0, 0), 
+        |(zeros, ones), n| if n >> place & 1 == 0 {
+            (zeros + 1, ones)
+        } else {
+            (zeros, ones + 1)
+        });
+
+    if total_ones >= total_zeros { 1 } else { 0 }
+}
+
+fn filter_nums(
+    rating: &'static str,
+    mut numbers: Vec<u32>,
+    mut place: u32,
+    inverted: bool
+) -> Result<u32, Error> {
+    while numbers.len() > 1 {
+        let mut valid_bit = most_common_bit(&numbers, place);
+        if inverted { valid_bit = !valid_bit & 1 }
+        numbers.retain(|&n| n >> place & 1 == valid_bit);
+        TRACE.detail("filtered", || vec![
+            ("rating", rating.to_string()),
+            ("bit", place.to_string()),
+            ("kept", valid_bit.to_string()),
+            ("remaining", numbers.len().to_string()),
+        ]);
+
+        if place == 0 { break }
+
+        place -= 1;
+    }
+
+    match numbers[..] {
+        [rating] => Ok(rating),
+        _ => Err(Error::NoUniqueRating { rating, remaining: numbers.len() }),
+    }
+}
+
+/// The oxygen generator and CO2 scrubber ratings, each found by filtering
+/// the numbers bit by bit.
+pub fn life_support_ratings(report: &Report) -> Result<(u32, u32), Error> {
+    let place = report.width as u32 - 1;
+
+    let oxygen = filter_nums("oxygen", report.numbers.clone(), place, false)?;
+    let co2 = filter_nums("CO2", report.numbers.clone(), place, true)?;
+
+    Ok((oxygen, co2))
+}
+
+/// The life support rating: the oxygen generator rating times the CO2
+/// scrubber rating, as a `u64` for the same reason as `problem_1`.
+pub fn problem_2(report: &Report) -> Result<u64, Error> {
+    let (oxygen, co2) = life_support_ratings(report)?;
+    Ok(oxygen as u64 * co2 as u64)
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Report;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input)
+    }
+
+    fn diagnostics(input: &Self::Input<'_>, part: u8) -> Vec<(&'static str, String)> {
+        let values = match part {
+            1 => {
+                let (gamma, epsilon) = power_rates(input);
+                vec![("gamma", gamma), ("epsilon", epsilon)]
+            }
+            _ => match life_support_ratings(input) {
+                Ok((oxygen, co2)) => vec![("oxygen", oxygen), ("co2", co2)],
+                Err(_) => Vec::new(),
+            },
+        };
+
+        values.into_iter()
+            .map(|(name, value)| (name, value.to_string()))
+            .collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let report = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(&report), 198);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let report = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(&report).unwrap(), 230);
+    }
+
+    #[test]
+    fn reports_bad_bit() {
+        let err = parse_input("00100\n11210").unwrap_err();
+        assert_eq!(err, Error::InvalidBit { location: Location::new(2, 3), found: '2' });
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        let report = parse_input(&input).unwrap();
+
+        assert!(problem_2(&report).is_ok());
+    }
+}
@@ -0,0 +1,60 @@
+//! Random bingo games for stress testing.
+
+use aoc_common::rng::Rng;
+
+use crate::BOARD_WIDTH;
+
+
+pub const DEFAULT_SIZE: usize = 100;
+
+const NUMBERS: u32 = 100;
+
+/// The turn on which `board` wins when the numbers come out as `turns` says.
+fn winning_turn(board: &[u32], turns: &[usize]) -> usize {
+    let turn = |x: usize, y: usize| turns[board[y * BOARD_WIDTH + x] as usize];
+    let rows = (0..BOARD_WIDTH).map(|y| (0..BOARD_WIDTH).map(|x| turn(x, y)).max());
+    let columns = (0..BOARD_WIDTH).map(|x| (0..BOARD_WIDTH).map(|y| turn(x, y)).max());
+
+    rows.chain(columns).flatten().min().expect("boards aren't empty")
+}
+
+/// Every number from 0 to 99 drawn in a random order, then `size` boards of
+/// distinct numbers. Since everything is drawn, every board wins eventually,
+/// and boards are redrawn until a single one wins last, as part 2 needs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut draws: Vec<u32> = (0..NUMBERS).collect();
+    rng.shuffle(&mut draws);
+    let mut turns = vec![0; NUMBERS as usize];
+    for (turn, &n) in draws.iter().enumerate() {
+        turns[n as usize] = turn;
+    }
+
+    let mut board = || {
+        let mut numbers: Vec<u32> = (0..NUMBERS).collect();
+        rng.shuffle(&mut numbers);
+        numbers.truncate(BOARD_WIDTH * BOARD_WIDTH);
+        numbers
+    };
+    let mut boards: Vec<Vec<u32>> = (0..size).map(|_| board()).collect();
+
+    loop {
+        let wins: Vec<usize> = boards.iter().map(|b| winning_turn(b, &turns)).collect();
+        let last = wins.iter().copied().max().unwrap_or_default();
+        let tied: Vec<usize> = (0..boards.len()).filter(|&i| wins[i] == last).collect();
+        if tied.len() < 2 { break }
+
+        boards[tied[0]] = board();
+    }
+
+    let draws = draws.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+    let boards = boards.iter()
+        .map(|numbers| numbers
+            .chunks(BOARD_WIDTH)
+            .map(|row| row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    format!("{}\n\n{}", draws, boards)
+}
@@ -0,0 +1,266 @@
+//! Day 4: Giant Squid. Plays bingo against a set of boards to find the one that
+//! wins first and the one that wins last.
+
+use std::fmt::Display;
+
+use aoc_common::{checked::{self, Overflow}, parse, trace::Tracer, Solution};
+use nom::{IResult, bytes::complete::tag, multi::separated_list1, sequence::{preceded, separated_pair}, character::complete::{space1, multispace0, multispace1, space0}};
+
+pub mod generate;
+
+
+const TRACE: Tracer = Tracer::new(4);
+
+
+/// Boards are always square, this many numbers on a side.
+pub const BOARD_WIDTH: usize = 5;
+
+/// Why the bingo game couldn't be parsed or played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The draws or boards aren't lists of numbers.
+    Parse(parse::Error),
+    /// A board (counting from 1) that isn't 5 by 5.
+    BoardSize { board: usize, found: usize },
+    /// The draws run out before any board wins.
+    NoWinningBoard,
+    /// The draws run out before every board has won.
+    NoLastBoard,
+    /// A board's score got too big.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::BoardSize { board, found } => write!(
+                f,
+                "board {} has {} numbers, expected {}",
+                board, found, BOARD_WIDTH * BOARD_WIDTH
+            ),
+            Error::NoWinningBoard => write!(f, "no board wins with the given draws"),
+            Error::NoLastBoard => write!(f, "the draws run out before every board has won"),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<parse::Error> for Error {
+    fn from(err: parse::Error) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+/// A bingo board and the numbers marked on it so far.
+#[derive(Debug, Clone)]
+pub struct Board {
+    numbers: Vec<u32>,
+    chosen: Vec<u32>,
+}
+
+impl Board {
+    /// A board with nothing marked, from its numbers row by row.
+    pub fn new(numbers: Vec<u32>) -> Self {
+        Board { numbers, chosen: Vec::new() }
+    }
+
+    pub fn numbers(&self) -> &[u32] {
+        &self.numbers
+    }
+
+    /// The sum of the unmarked numbers times the last number marked, or
+    /// `None` if nothing is marked yet. The sum can't overflow a `u64`, but
+    /// the product can.
+    pub fn score(&self) -> Result<Option<u64>, Overflow> {
+        let unmarked_sum: u64 = self.numbers.iter()
+            .filter(|n| !self.chosen.contains(n))
+            .map(|&n| n as u64)
+            .sum();
+
+        let Some(&last) = self.chosen.last() else { return Ok(None) };
+        checked::mul(last as u64, unmarked_sum).on("last draw * unmarked sum").map(Some)
+    }
+
+    /// Marks `test_num` if it's on the board.
+    pub fn add_chosen(&mut self, test_num: u32) {
+        if self.numbers.contains(&test_num) { self.chosen.push(test_num) }
+    }
+
+    /// Whether a whole row or column is marked.
+    pub fn is_cleared(&self) -> bool {
+        (0..BOARD_WIDTH)
+            .any(|n| self.check_row(n) || self.check_column(n))
+    }
+
+    fn check_row(&self, row: usize) -> bool {
+        self.numbers[row * BOARD_WIDTH..(row + 1) * BOARD_WIDTH]
+            .iter()
+            .all(|n| self.chosen.contains(n))
+    }
+
+    fn check_column(&self, column: usize) -> bool {
+        let mut pos = column;
+
+        while let Some(item) = self.numbers.get(pos) {
+            if !self.chosen.contains(item) { return false }
+            pos += BOARD_WIDTH;
+        }
+        
+        true
+    }
+}
+
+
+fn get_draws(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(tag(","), parse::u32)(input)
+}
+
+fn get_boards(input: &str) -> IResult<&str, Vec<Board>> {
+    separated_list1(multispace1, get_board)(input)
+}
+
+fn get_board(input: &str) -> IResult<&str, Board> {
+    let (input, working) = parse::lines(get_board_line)(input)?;
+    let working = working.iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+    let board = Board::new(working);
+
+    Ok((input, board))
+}
+
+fn get_board_line(input: &str) -> IResult<&str, Vec<u32>> {
+    preceded(space0, separated_list1(space1, parse::u32))(input)
+}
+
+
+/// Parses the comma separated draws, then the boards, each one a block of
+/// whitespace separated rows.
+pub fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<Board>), Error> {
+    let (draws, boards) = parse::complete(input, separated_pair(get_draws, multispace0, get_boards))?;
+
+    if let Some((i, board)) = boards.iter()
+        .enumerate()
+        .find(|(_, b)| b.numbers.len() != BOARD_WIDTH * BOARD_WIDTH) {
+        return Err(Error::BoardSize { board: i + 1, found: board.numbers.len() })
+    }
+
+    Ok((draws, boards))
+}
+
+
+fn find_winning_board(draws: Vec<u32>, mut boards: Vec<Board>) -> Result<Board, Error> {
+    for draw in draws {
+        boards.iter_mut().for_each(|b| b.add_chosen(draw));
+        if let Some(winner) = boards.iter().position(|b| b.is_cleared()) {
+            TRACE.info("board won", || vec![("board", (winner + 1).to_string()), ("draw", draw.to_string())]);
+            return Ok(boards.swap_remove(winner))
+        }
+    }
+    
+    Err(Error::NoWinningBoard)
+}
+
+fn find_losingest_board(draws: Vec<u32>, boards: Vec<Board>) -> Result<Board, Error> {
+    // numbered as in the input, for tracing
+    let mut boards: Vec<(usize, Board)> = boards.into_iter().enumerate().collect();
+    for draw in draws {
+        boards.iter_mut().for_each(|(_, b)| b.add_chosen(draw));
+        for (number, _) in boards.iter().filter(|(_, b)| b.is_cleared()) {
+            TRACE.detail("board won", || vec![("board", (number + 1).to_string()), ("draw", draw.to_string())]);
+        }
+        if boards.len() == 1 && boards[0].1.is_cleared() {
+            let (number, last) = boards.remove(0);
+            TRACE.info("last board won", || vec![("board", (number + 1).to_string()), ("draw", draw.to_string())]);
+            return Ok(last);
+        }
+        boards.retain(|(_, b)| !b.is_cleared())
+    }
+
+    Err(Error::NoLastBoard)
+}
+
+/// The score of the first board to win.
+pub fn problem_1(draws: Vec<u32>, boards: Vec<Board>) -> Result<u64, Error> {
+    Ok(find_winning_board(draws, boards)?.score()?.expect("a cleared board has marks"))
+}
+
+/// The score of the last board to win.
+pub fn problem_2(draws: Vec<u32>, boards: Vec<Board>) -> Result<u64, Error> {
+    Ok(find_losingest_board(draws, boards)?.score()?.expect("a cleared board has marks"))
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+
+    type Input<'a> = (Vec<u32>, Vec<Board>);
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        let (draws, boards) = input;
+        problem_1(draws.clone(), boards.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        let (draws, boards) = input;
+        problem_2(draws.clone(), boards.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let (draws, boards) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(draws, boards).unwrap(), 4512);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let (draws, boards) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(draws, boards).unwrap(), 1924);
+    }
+
+    #[test]
+    fn reports_short_board() {
+        let input = EXAMPLE.replacen(" 1 12 20 15 19", " 1 12 20 15", 1);
+        assert_eq!(parse_input(&input).unwrap_err(), Error::BoardSize { board: 1, found: 24 });
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        let (draws, boards) = parse_input(&input).unwrap();
+        assert!(problem_1(draws.clone(), boards.clone()).is_ok());
+        assert!(problem_2(draws, boards).is_ok());
+    }
+}
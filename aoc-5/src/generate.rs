@@ -0,0 +1,35 @@
+//! Random hydrothermal vent maps for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 500;
+
+const EXTENT: i64 = 1000;
+
+/// `size` horizontal, vertical or 45 degree vents, one per line, on a
+/// 1000x1000 floor. Every vent covers at least two points.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const DIRECTIONS: [(i64, i64); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+
+    (0..size)
+        .map(|_| loop {
+            let (dx, dy) = *rng.choose(&DIRECTIONS);
+            let (x1, y1) = (rng.range(0..EXTENT as u64) as i64, rng.range(0..EXTENT as u64) as i64);
+
+            // the longest run from (x1, y1) that stays on the floor
+            let room_x = if dx > 0 { EXTENT - 1 - x1 } else { i64::MAX };
+            let room_y = match dy {
+                1 => EXTENT - 1 - y1,
+                -1 => y1,
+                _ => i64::MAX,
+            };
+            let room = room_x.min(room_y);
+            if room == 0 { continue }
+
+            let length = rng.range(1..room as u64 + 1) as i64;
+            break format!("{},{} -> {},{}", x1, y1, x1 + dx * length, y1 + dy * length)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
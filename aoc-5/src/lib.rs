@@ -0,0 +1,223 @@
+//! Day 5: Hydrothermal Venture. Rasterises lines of vents onto the ocean floor
+//! and counts the points where they overlap.
+
+use std::{fmt::Display, collections::HashMap, iter};
+
+use aoc_common::{parse, Solution};
+use nom::IResult;
+use num::range_step_inclusive;
+
+pub mod generate;
+
+
+/// Why the vents couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't `x1,y1 -> x2,y2`.
+    Parse(parse::Error),
+    /// A vent that's neither straight nor diagonal.
+    Skewed { line: usize },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::Skewed { line } =>
+                write!(f, "line {}: vent is neither straight nor at 45 degrees", line),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<parse::Error> for Error {
+    fn from(err: parse::Error) -> Self {
+        Error::Parse(err)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Point {
+    pub fn new(x: u32, y: u32) -> Self {
+        Point { x, y }
+    }
+}
+
+/// A line of vents from `a` to `b`, both ends included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub a: Point,
+    pub b: Point,
+}
+
+/// Coordinates go up to `u32::MAX`, so the stepping is done in `i64`.
+fn step_between(a: u32, b: u32) -> Box<dyn Iterator<Item = u32>> {
+    let (i_a, i_b) = (a as i64, b as i64);
+    let step = (i_b - i_a).signum();    
+
+    if a == b {
+        Box::new(iter::repeat(a))
+    } else {
+        Box::new(range_step_inclusive(i_a, i_b, step)
+            .map(|n| n as u32))
+    }
+}
+
+impl Line {
+    pub fn new(a: Point, b: Point) -> Self {
+        Line { a, b }
+    }
+
+    pub fn is_straight(&self) -> bool {
+        self.a.x == self.b.x || self.a.y == self.b.y
+    }
+
+    /// Every point the line covers, from `a` to `b`. Only makes sense for
+    /// straight and 45 degree lines, which is all `parse_input` lets through.
+    pub fn iter_intersecting(&self) -> impl Iterator<Item = Point> {
+        let length = self.a.x.abs_diff(self.b.x).max(self.a.y.abs_diff(self.b.y)) as u64 + 1;
+
+        Iterator::zip(
+            step_between(self.a.x, self.b.x),
+            step_between(self.a.y, self.b.y)
+        )
+            .take(length as usize)
+            .map(|(x,y)| Point::new(x, y))
+    }
+}
+
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.a, self.b)
+    }
+}
+
+
+fn get_overlap_count<'a, I>(lines: I) -> u32
+where
+    I: Iterator<Item = &'a Line>
+{
+    let mut overlaps: HashMap<Point, u32> = HashMap::new();
+
+    for line in lines {
+        for point in line.iter_intersecting() {
+            *overlaps.entry(point).or_insert(0) += 1
+        }
+    }
+
+    overlaps.values()
+        .filter(|&&overlap_num| overlap_num >= 2)
+        .count() as u32
+}
+
+/// How many points at least two horizontal or vertical lines cover.
+pub fn problem_1(lines: &[Line]) -> u32 {
+    let lines = lines.iter()
+        .filter(|&l| l.is_straight());
+    get_overlap_count(lines)
+}
+
+/// How many points at least two lines cover, diagonals included.
+pub fn problem_2(lines: &[Line]) -> u32 { 
+    get_overlap_count(lines.iter()) 
+}
+
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+    let (output, (x, y)) = parse::separated(parse::u32, ",", parse::u32)(input)?;
+    Ok((output, Point::new(x, y)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line> {
+    let (output, (a, b)) = parse::separated(parse_point, " -> ", parse_point)(input)?;
+    Ok((output, Line::new(a, b)))
+}
+
+/// Parses one `x,y -> x,y` line per line of input, rejecting any that aren't
+/// straight or at 45 degrees.
+pub fn parse_input(input: &str) -> Result<Vec<Line>, Error> {
+    let lines = parse::complete(input, parse::lines(parse_line))?;
+
+    if let Some(i) = lines.iter().position(|l| {
+        let (dx, dy) = (l.a.x.abs_diff(l.b.x), l.a.y.abs_diff(l.b.y));
+        dx != 0 && dy != 0 && dx != dy
+    }) {
+        return Err(Error::Skewed { line: i + 1 })
+    }
+
+    Ok(lines)
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<Line>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_2(input))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let lines = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(&lines), 5);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let lines = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(&lines), 12);
+    }
+
+    #[test]
+    fn reports_bad_coordinate() {
+        let err = parse_input("0,9 -> 5,9\n8,0 -> 0;8").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 9: expected a separator");
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        assert!(parse_input(&input).is_ok());
+    }
+}
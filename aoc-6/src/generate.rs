@@ -0,0 +1,14 @@
+//! Random lanternfish schools for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 300;
+
+/// `size` comma separated timers between 1 and 5, like the puzzle's own.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| rng.range(1..6).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
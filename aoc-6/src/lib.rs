@@ -0,0 +1,211 @@
+//! Day 6: Lanternfish. Simulates a school of exponentially breeding fish by
+//! counting how many share each timer value.
+
+use std::{fmt::Display, num::ParseIntError};
+
+use aoc_common::{checked::{self, Overflow}, trace::Tracer, Location, Solution};
+
+pub mod generate;
+pub mod reference;
+
+
+const TRACE: Tracer = Tracer::new(6);
+
+
+/// The timer a newborn fish starts on.
+pub const MAX_TIMER: u8 = 8;
+
+/// Why the lanternfish couldn't be parsed or counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A timer that isn't a number.
+    InvalidTimer { location: Location, found: String, source: ParseIntError },
+    /// A timer above [`MAX_TIMER`].
+    TimerOutOfRange { location: Location, found: u8 },
+    /// There are too many fish to count.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidTimer { location, found, source } =>
+                write!(f, "{}: invalid timer {:?}: {}", location, found, source),
+            Error::TimerOutOfRange { location, found } =>
+                write!(f, "{}: timer {} is above {}", location, found, MAX_TIMER),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+
+/// How many fish there are with each timer value, from 0 to `MAX_TIMER`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fishes(Vec<u64>);
+
+impl Fishes {
+    /// Advances the school by one day.
+    pub fn next(&mut self) {
+        let new_fish = self.0.remove(0);
+        self.0[6] += new_fish;
+        self.0.push(new_fish);
+    }
+
+    /// Advances the school by `days` days at once. Rather than shifting every
+    /// count along each day, the day's newborns stay put in the slot their
+    /// parents emptied and the start of the timers moves round instead.
+    pub fn advance(&mut self, days: usize) -> Result<(), Overflow> {
+        let len = self.0.len();
+        for day in 0..days {
+            let newborn = self.0[day % len];
+            let parents = &mut self.0[(day + 7) % len];
+            *parents = checked::add(*parents, newborn).on("fish count")?;
+            TRACE.detail("generation", || vec![
+                ("days", (day + 1).to_string()),
+                ("timers", format!("{:?}", (0..len).map(|t| self.0[(day + 1 + t) % len]).collect::<Vec<_>>())),
+            ]);
+        }
+        self.0.rotate_left(days % len);
+        Ok(())
+    }
+
+    pub fn count(&self) -> Result<u64, Overflow> {
+        self.0.iter().try_fold(0, |total, &n| checked::add(total, n).on("total fish count"))
+    }
+}
+
+/// Builds a school from individual timers. Panics on a timer above `MAX_TIMER`.
+impl FromIterator<u8> for Fishes {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut fishes = vec![0_u64; MAX_TIMER as usize + 1];
+        for fish in iter {
+            *fishes.get_mut(fish as usize).unwrap() += 1;
+        }
+        Fishes(fishes)
+    }
+}   
+
+/// Parses the comma separated timers.
+pub fn parse_input(input: &str) -> Result<Fishes, Error> {
+    let timers = input.trim_end().split(',')
+        .map(|n| {
+            let location = Location::from_slice(input, n);
+            match n.parse() {
+                Ok(timer) if timer > MAX_TIMER => Err(Error::TimerOutOfRange { location, found: timer }),
+                Ok(timer) => Ok(timer),
+                Err(source) => Err(Error::InvalidTimer { location, found: n.into(), source }),
+            }
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    Ok(timers.into_iter().collect())
+}
+
+/// How many fish there are after 80 days.
+pub fn problem_1(mut fishes: Fishes) -> Result<u64, Error> {
+    fishes.advance(80)?;
+    Ok(fishes.count()?)
+}
+
+/// How many fish there are after 256 days.
+pub fn problem_2(mut fishes: Fishes) -> Result<u64, Error> {
+    fishes.advance(256)?;
+    Ok(fishes.count()?)
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Fishes;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{differential, rng::Rng};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        assert_eq!(problem_1(parse_input(EXAMPLE).unwrap()).unwrap(), 5934);
+    }
+
+    #[test]
+    fn example_part_2() {
+        assert_eq!(problem_2(parse_input(EXAMPLE).unwrap()).unwrap(), 26984457539);
+    }
+
+    #[test]
+    fn reports_timer_out_of_range() {
+        let err = parse_input("3,4,9,1").unwrap_err();
+        assert_eq!(err, Error::TimerOutOfRange { location: Location::new(1, 5), found: 9 });
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        assert!(parse_input(&input).is_ok());
+    }
+
+    #[test]
+    fn advance_matches_reference() {
+        let result = differential::check(
+            &differential::Config::default(),
+            |rng, size| {
+                let timers: Vec<u8> = generate::generate(rng, size).split(',')
+                    .map(|n| n.parse().unwrap())
+                    .collect();
+                (timers, rng.range(0..257) as usize)
+            },
+            |(timers, days)| {
+                let fewer_timers = differential::shrink_vec(timers).into_iter().map(|t| (t, *days));
+                let fewer_days = differential::shrink_number(*days as u64).into_iter()
+                    .map(|d| (timers.clone(), d as usize));
+                fewer_timers.chain(fewer_days).collect()
+            },
+            |(timers, days)| {
+                let mut fishes: Fishes = timers.iter().copied().collect();
+                reference::advance(&mut fishes, *days);
+                fishes
+            },
+            |(timers, days)| {
+                let mut fishes: Fishes = timers.iter().copied().collect();
+                fishes.advance(*days).expect("a few hundred fish don't overflow");
+                fishes
+            },
+        );
+
+        if let Err(mismatch) = result {
+            panic!("{}", mismatch);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! The straightforward day by day simulation, kept to check the faster one in
+//! the crate root against.
+
+use crate::{Error, Fishes};
+
+
+pub fn advance(fishes: &mut Fishes, days: usize) {
+    for _ in 0..days {
+        fishes.next();
+    }
+}
+
+pub fn problem_1(mut fishes: Fishes) -> Result<u64, Error> {
+    advance(&mut fishes, 80);
+    Ok(fishes.count()?)
+}
+
+pub fn problem_2(mut fishes: Fishes) -> Result<u64, Error> {
+    advance(&mut fishes, 256);
+    Ok(fishes.count()?)
+}
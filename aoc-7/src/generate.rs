@@ -0,0 +1,17 @@
+//! Random crab positions for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` comma separated positions. The spread grows with `size` so the
+/// range of candidate positions does too.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let spread = 2 * size as u64 + 1;
+
+    (0..size)
+        .map(|_| rng.range(0..spread).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,178 @@
+//! Day 7: The Treachery of Whales. Finds the position the crabs can line up on
+//! for the least fuel.
+
+use std::{fmt::Display, num::ParseIntError};
+
+use aoc_common::{checked::{self, Overflow}, Location, Solution};
+
+pub mod generate;
+pub mod reference;
+
+
+/// Why the crabs couldn't be parsed or aligned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A position that isn't a whole number.
+    InvalidPosition { location: Location, found: String, source: ParseIntError },
+    /// There are no crabs at all.
+    NoCrabs,
+    /// The fuel cost got too big.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidPosition { location, found, source } =>
+                write!(f, "{}: invalid position {:?}: {}", location, found, source),
+            Error::NoCrabs => write!(f, "there are no crabs to align"),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+
+/// The fuel for every crab to reach `target` when moving `n` steps costs
+/// `cost(n)`. Worked out in `i64`, though far enough apart crabs can still
+/// overflow it.
+fn fuel(
+    input: &[i32],
+    target: i64,
+    cost: impl Fn(i64) -> Result<i64, Overflow>,
+) -> Result<i64, Overflow> {
+    input.iter().try_fold(0, |total, &v| {
+        let steps = (target - v as i64).abs();
+        checked::add(total, cost(steps)?).on("total fuel")
+    })
+}
+
+/// The least fuel to line up, when each step costs one. The total distance
+/// is smallest at the median.
+pub fn problem_1(mut input: Vec<i32>) -> Result<i64, Error> {
+    if input.is_empty() {
+        return Err(Error::NoCrabs)
+    }
+
+    let middle = input.len() / 2;
+    let (_, &mut median, _) = input.select_nth_unstable(middle);
+    Ok(fuel(&input, median as i64, Ok)?)
+}
+
+/// The least fuel to line up, when each step costs one more than the last.
+/// The best position is always within half a step of the mean, so only the
+/// whole numbers either side of it need trying.
+pub fn problem_2(input: Vec<i32>) -> Result<i64, Error> {
+    if input.is_empty() {
+        return Err(Error::NoCrabs)
+    }
+
+    let sum: i64 = input.iter().map(|&v| v as i64).sum();
+    let mean = sum.div_euclid(input.len() as i64);
+    let cost = |n: i64| Ok(checked::mul(n, n + 1).on("steps * (steps + 1)")? / 2);
+    Ok(i64::min(fuel(&input, mean, cost)?, fuel(&input, mean + 1, cost)?))
+}
+
+/// Parses the comma separated crab positions.
+pub fn parse_input(input: &str) -> Result<Vec<i32>, Error> {
+    input.trim_end().split(',')
+        .map(|n| n.parse::<i32>().map_err(|source| Error::InvalidPosition {
+            location: Location::from_slice(input, n),
+            found: n.into(),
+            source,
+        }))
+        .collect()
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<i32>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{differential, rng::Rng};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        assert_eq!(problem_1(parse_input(EXAMPLE).unwrap()).unwrap(), 37);
+    }
+
+    #[test]
+    fn example_part_2() {
+        assert_eq!(problem_2(parse_input(EXAMPLE).unwrap()).unwrap(), 168);
+    }
+
+    #[test]
+    fn reports_bad_position() {
+        let err = parse_input("16,1,x,0").unwrap_err();
+        assert!(matches!(err, Error::InvalidPosition { location: Location { line: 1, column: 6 }, .. }));
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        let positions = parse_input(&input).unwrap();
+        assert!(problem_1(positions.clone()).is_ok());
+        assert!(problem_2(positions).is_ok());
+    }
+
+    #[test]
+    fn optimised_matches_reference() {
+        let config = differential::Config { max_size: 100, ..Default::default() };
+        let shrink = |positions: &Vec<i32>| [
+            differential::shrink_vec(positions),
+            differential::shrink_each(positions, |&n| {
+                differential::shrink_number(n as u64).into_iter().map(|n| n as i32).collect()
+            }),
+        ].concat();
+
+        for (reference, optimised) in [
+            (reference::problem_1 as fn(Vec<i32>) -> _, problem_1 as fn(Vec<i32>) -> _),
+            (reference::problem_2, problem_2),
+        ] {
+            let result = differential::check(
+                &config,
+                |rng, size| parse_input(&generate::generate(rng, size)).unwrap(),
+                shrink,
+                |positions| reference(positions.clone()),
+                |positions| optimised(positions.clone()),
+            );
+
+            if let Err(mismatch) = result {
+                panic!("{}", mismatch);
+            }
+        }
+    }
+}
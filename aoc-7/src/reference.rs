@@ -0,0 +1,30 @@
+//! Tries every position between the outermost crabs, kept to check the
+//! shortcuts in the crate root against.
+
+use crate::Error;
+
+
+fn bounds(input: &[i32]) -> Result<(i32, i32), Error> {
+    let min = *input.iter().min().ok_or(Error::NoCrabs)?;
+    let max = *input.iter().max().ok_or(Error::NoCrabs)?;
+    Ok((min, max))
+}
+
+pub fn problem_1(input: Vec<i32>) -> Result<i64, Error> {
+    let (min, max) = bounds(&input)?;
+    Ok((min..=max).fold(i64::MAX,|acc, n| {
+        i64::min(acc, input.iter()
+            .map(|&v| (n as i64 - v as i64).abs())
+            .sum())
+    }))
+}
+
+pub fn problem_2(input: Vec<i32>) -> Result<i64, Error> {
+    let (min, max) = bounds(&input)?;
+    Ok((min..=max).fold(i64::MAX,|acc, n| {
+        i64::min(acc, input.iter()
+            .map(|&v| (n as i64 - v as i64).abs())
+            .map(|v| v * (v + 1) / 2)
+            .sum())
+    }))
+}
@@ -0,0 +1,45 @@
+//! Random scrambled seven-segment displays for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 200;
+
+/// The lit segments of 0 to 9 on an unscrambled display.
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Rewires `digit` and lists its segments in a random order.
+fn scramble(rng: &mut Rng, wiring: &[char], digit: &str) -> String {
+    let mut segments: Vec<char> = digit.chars()
+        .map(|c| wiring[(c as u8 - b'a') as usize])
+        .collect();
+    rng.shuffle(&mut segments);
+    segments.into_iter().collect()
+}
+
+/// `size` lines, each with its own wiring: the ten digit patterns in a
+/// random order, then four random output digits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut wiring: Vec<char> = "abcdefg".chars().collect();
+            rng.shuffle(&mut wiring);
+
+            let mut patterns: Vec<String> = DIGITS.iter()
+                .map(|digit| scramble(rng, &wiring, digit))
+                .collect();
+            rng.shuffle(&mut patterns);
+            let outputs: Vec<String> = (0..4)
+                .map(|_| {
+                    let digit = rng.choose(&DIGITS);
+                    scramble(rng, &wiring, digit)
+                })
+                .collect();
+
+            format!("{} | {}", patterns.join(" "), outputs.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
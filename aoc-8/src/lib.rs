@@ -0,0 +1,238 @@
+//! Day 8: Seven Segment Search. Works out how each display's segments are
+//! wired from its ten digit patterns, then reads the four digit output.
+
+use std::fmt::Display;
+
+use aoc_common::{checked::{self, Overflow}, parse, trace::Tracer, Solution};
+use nom::{IResult, multi::separated_list1, character::complete::{alpha1, char}};
+
+pub mod generate;
+
+
+const TRACE: Tracer = Tracer::new(8);
+
+
+/// Why the displays couldn't be parsed or decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't ten patterns, a `|` and four outputs.
+    Parse(parse::Error),
+    /// Patterns that no wiring of the segments explains.
+    Undecodable { line: usize },
+    /// An output that isn't one of its line's ten patterns.
+    UnknownPattern { line: usize, pattern: String },
+    /// An output value, or their sum, overflowed.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::Undecodable { line } =>
+                write!(f, "line {}: patterns don't map onto a seven-segment display", line),
+            Error::UnknownPattern { line, pattern } =>
+                write!(f, "line {}: output {:?} isn't one of the line's digits", line, pattern),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<parse::Error> for Error {
+    fn from(err: parse::Error) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+/// One line of input: a display's ten digit patterns, then its output.
+pub type RawDisplay<'a> = (Digits<'a>, Vec<&'a str>);
+
+/// The unidentified digit patterns of one display. Identifying a digit takes
+/// its pattern out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Digits<'a>(Vec<&'a str>);
+
+impl<'a> Digits<'a> {
+    pub fn new(patterns: Vec<&'a str>) -> Self {
+        Digits(patterns)
+    }
+
+    pub fn patterns(&self) -> &[&'a str] {
+        &self.0
+    }
+
+    fn find_by_size(&mut self, size: u32) -> Option<String> {
+        let index = self.0.iter()
+            .position(|n| n.len() == size as usize)?;
+        Some(self.0.remove(index).into())
+    }
+
+    fn find_by_subset(&mut self, subset: &str) -> Option<String> {
+        let index = self.0.iter()
+            .position(|n| subset.chars().all(|m| n.contains(m)))?;
+        Some(self.0.remove(index).into())
+    }
+
+    fn find_by_superset(&mut self, superset: &str) -> Option<String> {
+        let index = self.0.iter()
+            .position(|n| n.chars().all(|m| superset.contains(m)))?;
+        Some(self.0.remove(index).into())
+    }
+
+    fn find_by_size_and_subset(&mut self, size: u32, subset: &str) -> Option<String> {
+        let index = self.0.iter()
+            .position(|n| subset.chars().all(|m| n.contains(m)) && n.len() == size as usize)?;
+        Some(self.0.remove(index).into())
+    }
+}
+
+
+/// How many output digits are a 1, 4, 7 or 8, the digits with a unique number
+/// of segments.
+pub fn problem_1(input: Vec<RawDisplay>) -> u32 {
+    input.iter()
+        .fold(0, |acc, (_, out)| {
+            acc + out.iter()
+                .filter(|n| matches!(n.len(), 2 | 3 | 4 | 7))
+                .count() as u32
+        })
+}
+
+
+/// The pattern for each digit, indexed by the digit, or `None` if the patterns
+/// can't all be told apart. Uses up `digits`.
+pub fn get_display_code(digits: &mut Digits) -> Option<Vec<String>> {
+    let mut mapping = vec![String::from("");10];
+    mapping[1] = digits.find_by_size(2)?;
+    mapping[4] = digits.find_by_size(4)?;
+    mapping[7] = digits.find_by_size(3)?;
+    mapping[8] = digits.find_by_size(7)?;
+    mapping[9] = digits.find_by_subset(&mapping[4])?;
+    mapping[0] = digits.find_by_size_and_subset(6, &mapping[7])?;
+    mapping[6] = digits.find_by_size(6)?;
+    mapping[3] = digits.find_by_subset(&mapping[7])?;
+    mapping[5] = digits.find_by_superset(&mapping[9])?;
+    mapping[2] = digits.find_by_size(5)?;
+    Some(mapping)
+}
+
+/// Which digit `display` shows under `mapping`, in any segment order.
+pub fn decode_display(mapping: &[String], display: &str) -> Option<u32> {
+    mapping.iter().position(|n| {
+        n.chars().all(|m| display.contains(m)) && display.chars().all(|d| n.contains(d))
+    }).map(|n| n as u32)
+}
+
+/// A display's output as a number. Only a `u64` long output can overflow.
+fn decode_line(line: usize, (digits, displays): &mut RawDisplay) -> Result<u64, Error> {
+    let mapping = get_display_code(digits).ok_or(Error::Undecodable { line })?;
+    TRACE.detail("mapping", || vec![("line", line.to_string()), ("digits", mapping.join(","))]);
+    let output: u64 = displays.iter()
+        .rev()
+        .enumerate()
+        .try_fold(0, |total, (count, n)| -> Result<u64, Error> {
+            let digit = decode_display(&mapping, n)
+                .ok_or_else(|| Error::UnknownPattern { line, pattern: n.to_string() })?;
+            let place = checked::pow(10_u64, count as u32).at("10^digits", line)?;
+            let value = checked::mul(place, digit as u64).at("digit * place value", line)?;
+            Ok(checked::add(total, value).at("output value", line)?)
+        })?;
+    TRACE.detail("output", || vec![("line", line.to_string()), ("value", output.to_string())]);
+    Ok(output)
+}
+
+/// The sum of every display's decoded output.
+pub fn problem_2(mut input: Vec<RawDisplay>) -> Result<u64, Error> {
+    input.iter_mut()
+        .enumerate()
+        .try_fold(0, |total, (i, raw_display)| {
+            let value = decode_line(i + 1, raw_display)?;
+            Ok(checked::add(total, value).on("sum of outputs")?)
+        })
+}
+
+
+fn parse_words(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(char(' '), alpha1)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, RawDisplay<'_>> {
+    let (out, (digits, display)) = parse::separated(parse_words, " | ", parse_words)(input)?;
+
+    let digits = Digits::new(digits);
+    Ok((out, (digits, display)))
+}
+
+/// Parses one `<patterns> | <outputs>` line per display.
+pub fn parse_input(input: &str) -> Result<Vec<RawDisplay<'_>>, Error> {
+    Ok(parse::complete(input, parse::lines(parse_line))?)
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<RawDisplay<'a>>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(problem_1(input.clone()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        let parsed = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_1(parsed), 26);
+    }
+
+    #[test]
+    fn example_part_2() {
+        let parsed = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(problem_2(parsed).unwrap(), 61229);
+    }
+
+    #[test]
+    fn reports_undecodable_line() {
+        let input = "ab ab ab ab ab ab ab ab ab ab | ab ab ab ab";
+        let parsed = parse_input(input).unwrap();
+        assert_eq!(problem_2(parsed).unwrap_err(), Error::Undecodable { line: 1 });
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        assert!(problem_2(parse_input(&input).unwrap()).is_ok());
+    }
+}
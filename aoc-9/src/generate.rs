@@ -0,0 +1,17 @@
+//! Random heightmaps for stress testing.
+
+use aoc_common::rng::Rng;
+
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A `size` by `size` heightmap. Roughly a third of the cells are 9s, which
+/// splits the rest into plenty of basins of varied sizes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| (0..size)
+            .map(|_| if rng.chance(1, 3) { '9' } else { char::from(b'0' + rng.range(0..9) as u8) })
+            .collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
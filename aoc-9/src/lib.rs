@@ -0,0 +1,208 @@
+//! Day 9: Smoke Basin. Finds the low points of a heightmap and the basins that
+//! drain into them.
+
+use std::fmt::Display;
+
+use aoc_common::{checked::{self, Overflow}, trace::Tracer, Solution};
+use aoc_grid::{FlatGrid, ParseError};
+use itertools::Itertools;
+
+pub mod generate;
+
+
+const TRACE: Tracer = Tracer::new(9);
+
+
+/// Why the heightmap couldn't be parsed or searched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The heightmap isn't a rectangle of digits.
+    Grid(ParseError),
+    /// There are fewer than the three basins part 2 multiplies.
+    TooFewBasins { found: usize },
+    /// The sum of risk levels or the product of basin sizes overflowed.
+    Overflow(Overflow),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Grid(err) => write!(f, "{}", err),
+            Error::TooFewBasins { found } =>
+                write!(f, "found {} basins, need at least 3", found),
+            Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Grid(err)
+    }
+}
+
+impl From<Overflow> for Error {
+    fn from(err: Overflow) -> Self {
+        Error::Overflow(err)
+    }
+}
+
+
+/// Parses the heightmap, one row of single digit heights per line.
+pub fn parse_input(input: &str) -> Result<FlatGrid<u32>, Error> {
+    Ok(FlatGrid::from_digit_str(input)?)
+}
+
+/// The positions lower than all their orthogonal neighbours.
+pub fn low_points(input: &FlatGrid<u32>) -> impl Iterator<Item = usize> + '_ {
+    input.iter()
+        .enumerate()
+        .filter(|(pos, height)| input.get_neighbors(*pos).all(|n| n > height))
+        .map(|(pos, _)| pos)
+}
+
+/// The sum of each low point's height plus one.
+pub fn problem_1(input: FlatGrid<u32>) -> Result<u32, Error> {
+    Ok(low_points(&input).try_fold(0, |total, pos| {
+        let line = input.coords(pos).1 as usize + 1;
+        checked::add(total, input[pos] + 1).at("sum of risk levels", line)
+    })?)
+}
+
+trait RemoveBy<T> {
+    fn remove_eq(&mut self, other: &T)
+    where
+        T: PartialEq;
+}
+
+impl<T> RemoveBy<T> for Vec<T> {
+    fn remove_eq(&mut self, other: &T)
+    where
+        T: PartialEq 
+    {
+        let index = self.iter().position(|x| *x == *other);
+        if let Some(index) = index { self.remove(index); }
+    }
+}
+
+
+/// The size of every basin, that is every region walled off by 9s, largest
+/// first.
+pub fn basin_sizes(input: &FlatGrid<u32>) -> Vec<usize> {
+    let mut positions: Vec<usize> = (0..input.len()).collect();
+    let mut basins: Vec<usize> = vec![];
+
+    while !positions.is_empty() {
+        let test_pos = *positions.first().unwrap();
+
+        if *input.get(test_pos).unwrap() == 9 {
+            positions.remove(0);
+            continue;
+        }
+
+        let mut removals = vec![test_pos];
+
+        let mut working_size = 0;
+        while !removals.is_empty() {
+            working_size += removals.len();
+
+            for pos in removals.iter() {
+                positions.remove_eq(pos);
+            }
+
+            removals = removals.iter()
+                .flat_map(|r| input.get_neighbor_positions(*r))
+                .filter(|p| positions.contains(p))
+                .filter(|p| *input.get(*p).unwrap() != 9)
+                .unique()
+                .collect();
+        }
+        TRACE.detail("basin", || {
+            let (x, y) = input.coords(test_pos);
+            vec![
+                ("row", (y + 1).to_string()),
+                ("column", (x + 1).to_string()),
+                ("size", working_size.to_string()),
+            ]
+        });
+        basins.push(working_size);
+    }
+
+    basins.sort_by(|a, b| b.cmp(a));
+    TRACE.info("basins", || vec![
+        ("count", basins.len().to_string()),
+        ("largest", format!("{:?}", &basins[..basins.len().min(3)])),
+    ]);
+    basins
+}
+
+/// The sizes of the three largest basins multiplied together.
+pub fn problem_2(input: FlatGrid<u32>) -> Result<u64, Error> {
+    let basins = basin_sizes(&input);
+
+    match basins[..] {
+        [a, b, c, ..] => {
+            let product = checked::mul(a as u64, b as u64).on("product of basin sizes")?;
+            Ok(checked::mul(product, c as u64).on("product of basin sizes")?)
+        }
+        _ => Err(Error::TooFewBasins { found: basins.len() }),
+    }
+}
+
+
+/// This day behind the shared [`Solution`] interface.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 9;
+
+    type Input<'a> = FlatGrid<u32>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        problem_2(input.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{rng::Rng, Location};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part_1() {
+        assert_eq!(problem_1(parse_input(EXAMPLE).unwrap()).unwrap(), 15);
+    }
+
+    #[test]
+    fn example_part_2() {
+        assert_eq!(problem_2(parse_input(EXAMPLE).unwrap()).unwrap(), 1134);
+    }
+
+    #[test]
+    fn reports_bad_height() {
+        let err = parse_input("2199\n39a7").unwrap_err();
+        assert_eq!(err, Error::Grid(ParseError::InvalidDigit { location: Location::new(2, 3), found: 'a' }));
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        let input = generate::generate(&mut Rng::new(1), generate::DEFAULT_SIZE);
+
+        assert!(problem_2(parse_input(&input).unwrap()).is_ok());
+    }
+}
@@ -0,0 +1,121 @@
+//! Arithmetic for the places a solver could overflow on a large enough input.
+//! Normally these are just the plain operators, but with the `checked`
+//! feature on they catch overflow and report the operation and the input
+//! line it happened on, rather than panicking or wrapping.
+//!
+//! ```
+//! use aoc_common::checked;
+//!
+//! let depth = checked::add(5_u32, 3).at("depth + distance", 2)?;
+//! # Ok::<(), checked::Overflow>(())
+//! ```
+
+use std::fmt::Display;
+
+
+/// An operation that overflowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// What was being worked out, such as `"depth + distance"`.
+    pub operation: &'static str,
+    /// The input line the operands came from, if they came from one.
+    pub line: Option<usize>,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {} overflowed", line, self.operation),
+            None => write!(f, "{} overflowed", self.operation),
+        }
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+
+/// The result of an operation that may have overflowed. Saying what the
+/// operation was turns it into a `Result`.
+#[must_use]
+pub struct Outcome<T>(Option<T>);
+
+impl<T> Outcome<T> {
+    /// For an operation on values from input line `line`.
+    pub fn at(self, operation: &'static str, line: usize) -> Result<T, Overflow> {
+        self.0.ok_or(Overflow { operation, line: Some(line) })
+    }
+
+    /// For an operation on values that don't come from any one line.
+    pub fn on(self, operation: &'static str) -> Result<T, Overflow> {
+        self.0.ok_or(Overflow { operation, line: None })
+    }
+}
+
+
+/// The integer types the operations work on.
+pub trait Integer: Copy {
+    fn add(self, rhs: Self) -> Option<Self>;
+    fn sub(self, rhs: Self) -> Option<Self>;
+    fn mul(self, rhs: Self) -> Option<Self>;
+    fn pow(self, exp: u32) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {$(
+        #[cfg(feature = "checked")]
+        impl Integer for $t {
+            fn add(self, rhs: Self) -> Option<Self> { self.checked_add(rhs) }
+            fn sub(self, rhs: Self) -> Option<Self> { self.checked_sub(rhs) }
+            fn mul(self, rhs: Self) -> Option<Self> { self.checked_mul(rhs) }
+            fn pow(self, exp: u32) -> Option<Self> { self.checked_pow(exp) }
+        }
+
+        #[cfg(not(feature = "checked"))]
+        impl Integer for $t {
+            fn add(self, rhs: Self) -> Option<Self> { Some(self + rhs) }
+            fn sub(self, rhs: Self) -> Option<Self> { Some(self - rhs) }
+            fn mul(self, rhs: Self) -> Option<Self> { Some(self * rhs) }
+            fn pow(self, exp: u32) -> Option<Self> { Some(<$t>::pow(self, exp)) }
+        }
+    )*};
+}
+
+integer!(u32, u64, usize, i32, i64);
+
+
+pub fn add<T: Integer>(a: T, b: T) -> Outcome<T> {
+    Outcome(a.add(b))
+}
+
+pub fn sub<T: Integer>(a: T, b: T) -> Outcome<T> {
+    Outcome(a.sub(b))
+}
+
+pub fn mul<T: Integer>(a: T, b: T) -> Outcome<T> {
+    Outcome(a.mul(b))
+}
+
+pub fn pow<T: Integer>(base: T, exp: u32) -> Outcome<T> {
+    Outcome(base.pow(exp))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_range_results_pass_through() {
+        assert_eq!(add(2_u32, 3).on("a + b"), Ok(5));
+        assert_eq!(sub(2_i64, 3).at("a - b", 4), Ok(-1));
+        assert_eq!(pow(10_u64, 3).on("10^n"), Ok(1000));
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn overflow_names_the_operation_and_line() {
+        let err = mul(u32::MAX, 2).at("depth * aim", 7).unwrap_err();
+        assert_eq!(err, Overflow { operation: "depth * aim", line: Some(7) });
+        assert_eq!(err.to_string(), "line 7: depth * aim overflowed");
+    }
+}
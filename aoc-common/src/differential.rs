@@ -0,0 +1,168 @@
+//! Differential testing: runs a slow but obviously correct reference solver
+//! and an optimised one over many generated inputs, and shrinks any input
+//! they disagree on down to a minimal one.
+
+use std::fmt::{Debug, Display};
+
+use crate::rng::Rng;
+
+
+/// Gives up shrinking after this many successful steps, in case a shrinker
+/// keeps offering inputs that aren't really any smaller.
+const MAX_SHRINKS: usize = 10_000;
+
+pub struct Config {
+    /// How many inputs to try.
+    pub cases: usize,
+    /// Case `n` is generated from `seed + n`.
+    pub seed: u64,
+    /// Inputs grow from size 1 on the first case to this on the last.
+    pub max_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { cases: 1000, seed: 0, max_size: 30 }
+    }
+}
+
+
+/// An input the two solvers disagree on.
+#[derive(Debug, Clone)]
+pub struct Mismatch<I, O> {
+    /// The seed and size that generated `original`.
+    pub seed: u64,
+    pub size: usize,
+    pub original: I,
+    /// `original` after shrinking.
+    pub input: I,
+    pub reference: O,
+    pub optimised: O,
+}
+
+impl<I: Debug, O: Debug> Display for Mismatch<I, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "solvers disagree on {:?}: reference gave {:?}, optimised gave {:?} (shrunk from seed {}, size {}: {:?})",
+            self.input, self.reference, self.optimised, self.seed, self.size, self.original
+        )
+    }
+}
+
+impl<I: Debug, O: Debug> std::error::Error for Mismatch<I, O> {}
+
+
+/// Runs both solvers over `config.cases` generated inputs. On the first
+/// disagreement, repeatedly swaps the input for the first of its `shrink`
+/// candidates that still disagrees, until none do.
+pub fn check<I, O>(
+    config: &Config,
+    generate: impl Fn(&mut Rng, usize) -> I,
+    shrink: impl Fn(&I) -> Vec<I>,
+    reference: impl Fn(&I) -> O,
+    optimised: impl Fn(&I) -> O,
+) -> Result<(), Mismatch<I, O>>
+where
+    I: Clone,
+    O: PartialEq,
+{
+    let disagreement = |input: &I| {
+        let (expected, found) = (reference(input), optimised(input));
+        (expected != found).then_some((expected, found))
+    };
+
+    for case in 0..config.cases {
+        let seed = config.seed.wrapping_add(case as u64);
+        let size = 1 + case * config.max_size.saturating_sub(1) / config.cases.max(2).saturating_sub(1);
+        let original = generate(&mut Rng::new(seed), size);
+
+        let Some(mut outputs) = disagreement(&original) else { continue };
+        let mut input = original.clone();
+
+        'shrink: for _ in 0..MAX_SHRINKS {
+            for candidate in shrink(&input) {
+                if let Some(found) = disagreement(&candidate) {
+                    input = candidate;
+                    outputs = found;
+                    continue 'shrink
+                }
+            }
+            break
+        }
+
+        let (reference, optimised) = outputs;
+        return Err(Mismatch { seed, size, original, input, reference, optimised })
+    }
+
+    Ok(())
+}
+
+
+/// Shorter copies of `items`: with each half removed, then each quarter, and
+/// so on down to each single item.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut out = Vec::new();
+    let mut chunk = items.len();
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let end = (start + chunk).min(items.len());
+            out.push([&items[..start], &items[end..]].concat());
+        }
+        chunk /= 2;
+    }
+    out
+}
+
+/// Copies of `items` with one item swapped for one of its `shrink` candidates.
+pub fn shrink_each<T: Clone>(items: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    items.iter().enumerate()
+        .flat_map(|(i, item)| shrink(item).into_iter().map(move |smaller| (i, smaller)))
+        .map(|(i, smaller)| {
+            let mut items = items.to_vec();
+            items[i] = smaller;
+            items
+        })
+        .collect()
+}
+
+/// Numbers between 0 and `n`, nearest 0 first.
+pub fn shrink_number(n: u64) -> Vec<u64> {
+    let mut out = vec![0, n / 2, n.saturating_sub(1)];
+    out.retain(|&m| m < n);
+    out.dedup();
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agreeing_solvers_pass() {
+        let result = check(
+            &Config::default(),
+            |rng, size| (0..size).map(|_| rng.range(0..100)).collect::<Vec<_>>(),
+            |v| shrink_vec(v),
+            |v| v.iter().sum::<u64>(),
+            |v| v.iter().rev().sum::<u64>(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn disagreements_shrink_to_a_minimal_input() {
+        // "forgets" any number above 50
+        let err = check(
+            &Config::default(),
+            |rng, size| (0..size).map(|_| rng.range(0..100)).collect::<Vec<_>>(),
+            |v| [shrink_vec(v), shrink_each(v, |&n| shrink_number(n))].concat(),
+            |v| v.iter().sum::<u64>(),
+            |v| v.iter().filter(|&&n| n <= 50).sum::<u64>(),
+        ).unwrap_err();
+
+        assert_eq!(err.input, vec![51]);
+        assert_eq!((err.reference, err.optimised), (51, 0));
+    }
+}
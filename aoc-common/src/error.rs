@@ -0,0 +1,44 @@
+use std::fmt::Display;
+
+
+/// A 1-based line and column in a puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Location { line, column }
+    }
+
+    /// The location of byte `offset` in `input`.
+    pub fn from_offset(input: &str, offset: usize) -> Self {
+        let before = &input[..offset.min(input.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |n| n + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Location { line, column }
+    }
+
+    /// The location where `rest`, a suffix of `input`, starts. This is how
+    /// nom reports where a parser gave up.
+    pub fn from_rest(input: &str, rest: &str) -> Self {
+        Location::from_offset(input, input.len() - rest.len())
+    }
+
+    /// The location where `slice`, a subslice of `input` such as one
+    /// returned by `split`, starts.
+    pub fn from_slice(input: &str, slice: &str) -> Self {
+        let offset = (slice.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        Location::from_offset(input, offset)
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
@@ -0,0 +1,115 @@
+use std::{env, fmt::Display, fs::{self, File}, io::{self, BufRead, BufReader, Read}, path::{Path, PathBuf}};
+
+
+/// Directory searched for `day-NN.txt` when no explicit source is given.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `inputs/day-NN.txt` relative to the working directory.
+    Default(u8),
+    /// An explicit file path.
+    Path(PathBuf),
+    /// Standard input, selected with a path of `-`.
+    Stdin,
+}
+
+impl Source {
+    /// Picks the source from an optional command line argument, where `-` means stdin.
+    pub fn from_arg(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Default(day),
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+        }
+    }
+
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Source::Default(day) => Some(default_path(*day)),
+            Source::Path(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.path() {
+            Some(path) => write!(f, "{}", path.display()),
+            None => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Missing(Source),
+    Unreadable(Source, io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Missing(source @ Source::Default(day)) => write!(
+                f,
+                "no input for day {} at {}, pass a path or `-` for stdin",
+                day, source
+            ),
+            Error::Missing(source) => write!(f, "input file {} does not exist", source),
+            Error::Unreadable(source, err) => write!(f, "could not read {}: {}", source, err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Missing(_) => None,
+            Error::Unreadable(_, err) => Some(err),
+        }
+    }
+}
+
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day-{:02}.txt", day))
+}
+
+pub fn load(source: &Source) -> Result<String, Error> {
+    match source.path() {
+        Some(path) => fs::read_to_string(&path).map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => Error::Missing(source.clone()),
+            _ => Error::Unreadable(source.clone(), err),
+        }),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)
+                .map_err(|err| Error::Unreadable(source.clone(), err))?;
+            Ok(input)
+        }
+    }
+}
+
+/// Opens `source` for reading a line at a time, for inputs too long to load
+/// whole.
+pub fn open(source: &Source) -> Result<Box<dyn BufRead>, Error> {
+    match source.path() {
+        Some(path) => match File::open(&path) {
+            Ok(file) => Ok(Box::new(BufReader::new(file))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Err(Error::Missing(source.clone())),
+            Err(err) => Err(Error::Unreadable(source.clone(), err)),
+        },
+        None => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+/// Loads the input named by the first command line argument, exiting with a
+/// message if it can't be read. Meant for the per-day binaries.
+pub fn from_args(day: u8) -> String {
+    let arg = env::args().nth(1);
+    let source = Source::from_arg(day, arg.as_deref());
+
+    crate::unwrap_or_exit(load(&source))
+}
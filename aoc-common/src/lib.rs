@@ -0,0 +1,31 @@
+//! Plumbing shared by every day: loading input, locating errors, nom parsers,
+//! a seeded random number generator for generated inputs, overflow checked
+//! arithmetic, a harness for checking optimised solvers against reference
+//! ones and tracing of their intermediate state.
+
+use std::{fmt::Display, process};
+
+pub mod checked;
+pub mod differential;
+pub mod error;
+pub mod input;
+pub mod parse;
+pub mod rng;
+pub mod solution;
+pub mod trace;
+
+pub use error::Location;
+pub use solution::Solution;
+
+
+/// Unwraps `result`, or prints the error and exits. Meant for the per-day
+/// binaries, which have nothing better to do with a failure.
+pub fn unwrap_or_exit<T, E>(result: Result<T, E>) -> T
+where
+    E: Display
+{
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1)
+    })
+}
@@ -0,0 +1,162 @@
+//! nom combinators shared by the days that parse with nom, and a way to turn
+//! their failures into located errors.
+
+use std::fmt::Display;
+
+use nom::{
+    IResult, Parser,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, one_of},
+    combinator::{map_res, opt, recognize},
+    error::ErrorKind,
+    sequence::{pair, separated_pair},
+};
+
+use crate::Location;
+
+
+/// Why an input didn't parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A parser gave up at `location`.
+    Unexpected { location: Location, kind: ErrorKind },
+    /// Everything parsed, but there's more than whitespace left over.
+    TrailingInput { location: Location },
+}
+
+impl Error {
+    /// Where in the input it went wrong.
+    pub fn location(&self) -> Location {
+        match self {
+            Error::Unexpected { location, .. } | Error::TrailingInput { location } => *location,
+        }
+    }
+
+    fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) =>
+                Error::Unexpected { location: Location::from_rest(input, e.input), kind: e.code },
+            nom::Err::Incomplete(_) =>
+                Error::Unexpected { location: Location::from_offset(input, input.len()), kind: ErrorKind::Eof },
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Unexpected { location, kind } => match kind {
+                ErrorKind::Digit => write!(f, "{}: expected a number", location),
+                ErrorKind::MapRes => write!(f, "{}: number out of range", location),
+                ErrorKind::Tag | ErrorKind::Char => write!(f, "{}: expected a separator", location),
+                ErrorKind::Alpha => write!(f, "{}: expected a letter", location),
+                ErrorKind::Eof => write!(f, "{}: unexpected end of input", location),
+                kind => write!(f, "{}: unexpected input ({})", location, kind.description()),
+            },
+            Error::TrailingInput { location } =>
+                write!(f, "{}: unexpected input after the last entry", location),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+
+/// Runs `parser` over the whole of `input`, allowing only whitespace to be
+/// left over.
+pub fn complete<'a, O, F>(input: &'a str, mut parser: F) -> Result<O, Error>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>
+{
+    let (rest, out) = parser.parse(input).map_err(|e| Error::from_nom(input, e))?;
+
+    if !rest.trim().is_empty() {
+        return Err(Error::TrailingInput { location: Location::from_rest(input, rest.trim_start()) })
+    }
+
+    Ok(out)
+}
+
+
+/// An unsigned decimal number.
+pub fn u32(input: &str) -> IResult<&str, u32> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// A decimal number with an optional `+` or `-` sign.
+pub fn i64(input: &str) -> IResult<&str, i64> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// `first`, then the literal `separator`, then `second`.
+pub fn separated<'a, O1, O2, F, G>(
+    first: F,
+    separator: &'a str,
+    second: G,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (O1, O2)>
+where
+    F: Parser<&'a str, O1, nom::error::Error<&'a str>>,
+    G: Parser<&'a str, O2, nom::error::Error<&'a str>>,
+{
+    separated_pair(first, tag(separator), second)
+}
+
+/// One or more `line`s separated by `\n` or `\r\n`, up to a blank line or the
+/// end of the input. The line ending after the last line is consumed.
+///
+/// Once the first line has parsed, a line that fails is a hard failure, so
+/// errors point into the bad line rather than at the start of the block.
+pub fn lines<'a, O, F>(mut line: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>
+{
+    move |input| {
+        let (mut input, first) = line.parse(input)?;
+        let mut out = vec![first];
+
+        loop {
+            let rest = match line_ending::<_, nom::error::Error<_>>(input) {
+                Ok((rest, _)) => rest,
+                Err(_) => return Ok((input, out)),
+            };
+            if rest.is_empty() || line_ending::<_, nom::error::Error<_>>(rest).is_ok() {
+                return Ok((rest, out))
+            }
+
+            let (rest, next) = line.parse(rest).map_err(|e| match e {
+                nom::Err::Error(e) => nom::Err::Failure(e),
+                e => e,
+            })?;
+            out.push(next);
+            input = rest;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_allow_crlf_and_a_trailing_newline() {
+        assert_eq!(complete("1\r\n2\n3\r\n", lines(u32)), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn numbers_report_where_they_failed() {
+        let err = complete("1,-2\n3,x", lines(separated(i64, ",", i64))).unwrap_err();
+        assert_eq!(err.location(), Location::new(2, 3));
+
+        let err = complete("99999999999", u32).unwrap_err();
+        assert_eq!(err, Error::Unexpected { location: Location::new(1, 1), kind: ErrorKind::MapRes });
+    }
+
+    #[test]
+    fn lines_stop_at_a_blank_line() {
+        assert_eq!(lines(u32)("1\n2\n\n3"), Ok(("\n3", vec![1, 2])));
+
+        let err = complete("1\n2\n\nnope", lines(u32)).unwrap_err();
+        assert_eq!(err, Error::TrailingInput { location: Location::new(4, 1) });
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use crate::{input, unwrap_or_exit};
+
+
+/// One day's puzzle: how to parse its input and solve both parts. Lets the
+/// runner, benches and tests drive any day the same way.
+pub trait Solution {
+    /// The day of the advent calendar.
+    const DAY: u8;
+
+    /// The parsed puzzle input, which may borrow from the text it came from.
+    type Input<'a>;
+
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Self::Error>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display, Self::Error>;
+
+    /// Named intermediate values behind a part's answer, such as the two
+    /// rates day 3 multiplies together. They're reported separately, so a
+    /// part's answer is the only thing it prints.
+    fn diagnostics(_input: &Self::Input<'_>, _part: u8) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+
+/// The whole of a day's binary: loads the input named on the command line,
+/// then prints both answers, or the first error and exits.
+pub fn run<S: Solution>() {
+    let input = input::from_args(S::DAY);
+    let parsed = unwrap_or_exit(S::parse(&input));
+
+    println!("{}", unwrap_or_exit(S::part1(&parsed)));
+    println!("{}", unwrap_or_exit(S::part2(&parsed)));
+}
@@ -0,0 +1,157 @@
+//! Structured events describing a solver's intermediate state, such as the
+//! draw that completed a bingo board, for following a solution step by step.
+//! Events go to a sink, stderr unless the runner sets another, and never to
+//! the answer output. Below the current level they aren't even built.
+//!
+//! ```
+//! use aoc_common::trace::Tracer;
+//!
+//! const TRACE: Tracer = Tracer::new(4);
+//!
+//! let (board, draw) = (3, 24);
+//! TRACE.info("board won", || vec![("board", board.to_string()), ("draw", draw.to_string())]);
+//! ```
+
+use std::{
+    fmt::Display,
+    sync::{atomic::{AtomicU8, Ordering}, RwLock},
+};
+
+
+/// How much to trace, each level including the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// A handful of events per part: results of the main steps.
+    Info,
+    /// Events inside the solver's loops, such as every fish generation.
+    Detail,
+}
+
+impl Level {
+    fn from_u8(level: u8) -> Self {
+        match level {
+            0 => Level::Off,
+            1 => Level::Info,
+            _ => Level::Detail,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Off => write!(f, "off"),
+            Level::Info => write!(f, "info"),
+            Level::Detail => write!(f, "detail"),
+        }
+    }
+}
+
+
+/// Something a solver did, with named values saying what.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub day: u8,
+    pub level: Level,
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {}: {}", self.day, self.name)?;
+        for (name, value) in &self.fields {
+            write!(f, " {}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+static SINK: RwLock<fn(&Event)> = RwLock::new(to_stderr);
+
+pub fn level() -> Level {
+    Level::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Sends events to `sink` from now on.
+pub fn set_sink(sink: fn(&Event)) {
+    *SINK.write().unwrap_or_else(|err| err.into_inner()) = sink;
+}
+
+/// The default sink: one line per event on stderr.
+pub fn to_stderr(event: &Event) {
+    eprintln!("{}", event);
+}
+
+
+/// Emits one day's events.
+pub struct Tracer {
+    day: u8,
+}
+
+impl Tracer {
+    pub const fn new(day: u8) -> Self {
+        Tracer { day }
+    }
+
+    pub fn info(&self, name: &'static str, fields: impl FnOnce() -> Vec<(&'static str, String)>) {
+        self.emit(Level::Info, name, fields)
+    }
+
+    pub fn detail(&self, name: &'static str, fields: impl FnOnce() -> Vec<(&'static str, String)>) {
+        self.emit(Level::Detail, name, fields)
+    }
+
+    fn emit(&self, level: Level, name: &'static str, fields: impl FnOnce() -> Vec<(&'static str, String)>) {
+        if level > self::level() {
+            return
+        }
+
+        let event = Event { day: self.day, level, name, fields: fields() };
+        let sink = *SINK.read().unwrap_or_else(|err| err.into_inner());
+        sink(&event);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    fn capture(event: &Event) {
+        EVENTS.lock().unwrap().push(format!("{} {}", event.level, event));
+    }
+
+    #[test]
+    fn events_below_the_level_are_dropped() {
+        const TRACE: Tracer = Tracer::new(6);
+        set_sink(capture);
+
+        set_level(Level::Info);
+        TRACE.info("counted", || vec![("fish", "26".into())]);
+        TRACE.detail("generation", || unreachable!("detail events aren't built at the info level"));
+
+        set_level(Level::Detail);
+        TRACE.detail("generation", || vec![("day", "1".into()), ("timers", "[0, 1]".into())]);
+
+        set_level(Level::Off);
+        TRACE.info("counted", || unreachable!("nothing is built with tracing off"));
+
+        assert_eq!(*EVENTS.lock().unwrap(), [
+            "info day 6: counted fish=26",
+            "detail day 6: generation day=1 timers=[0, 1]",
+        ]);
+    }
+}
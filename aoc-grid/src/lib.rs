@@ -0,0 +1,363 @@
+//! A rectangular grid stored in one `Vec`, with configurable neighbourhoods,
+//! shared by the days whose input is a grid of digits.
+
+use std::{fmt::Display, ops::{Index, IndexMut}};
+
+use aoc_common::Location;
+
+
+/// Which cells count as adjacent when looking up neighbours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The four orthogonal cells.
+    VonNeumann,
+    /// The eight orthogonal and diagonal cells.
+    Moore,
+    /// Any set of `(x, y)` offsets from the centre cell.
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    const VON_NEUMANN: &'static [(isize, isize)] = &[
+        ( 1, 0),
+        (-1, 0),
+        ( 0, 1),
+        ( 0,-1)
+    ];
+
+    const MOORE: &'static [(isize, isize)] = &[
+        (-1,-1),
+        ( 0,-1),
+        ( 1,-1),
+        (-1, 0),
+        ( 1, 0),
+        (-1, 1),
+        ( 0, 1),
+        ( 1, 1)
+    ];
+
+    /// The `(x, y)` offsets of the neighbouring cells.
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        match self {
+            Neighborhood::VonNeumann => Self::VON_NEUMANN,
+            Neighborhood::Moore => Self::MOORE,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+
+/// Why a block of digits isn't a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// There are no cells, or the first line is blank.
+    Empty,
+    /// A character that isn't a decimal digit.
+    InvalidDigit {
+        /// Where the character is.
+        location: Location,
+        /// The character itself.
+        found: char,
+    },
+    /// A line with a different number of cells to the first.
+    Ragged {
+        /// The 1-based line number.
+        line: usize,
+        /// How many cells the first line has.
+        expected: usize,
+        /// How many cells this line has.
+        found: usize,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "grid is empty"),
+            ParseError::InvalidDigit { location, found } =>
+                write!(f, "{}: expected a digit, found {:?}", location, found),
+            ParseError::Ragged { line, expected, found } =>
+                write!(f, "line {}: expected {} cells, found {}", line, expected, found),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatGrid<T> {
+    grid: Vec<T>,
+    width: usize,
+    height: usize,
+    neighborhood: Neighborhood,
+}
+
+impl<T> FlatGrid<T> {
+    /// Builds a grid with a von Neumann neighbourhood. Panics if `width` is 0
+    /// or `grid` doesn't hold `width * height` cells.
+    pub fn new(grid: Vec<T>, width: usize, height: usize) -> Self {
+        assert!(width > 0, "grid has no columns");
+        assert_eq!(grid.len(), width * height, "grid size doesn't match its dimensions");
+        FlatGrid { grid, width, height, neighborhood: Neighborhood::VonNeumann }
+    }
+
+    /// Changes which cells count as neighbours.
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// How many cells each row has.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// How many rows there are.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// How many cells there are.
+    pub fn len(&self) -> usize {
+        self.grid.len()
+    }
+
+    /// Whether there are no cells.
+    pub fn is_empty(&self) -> bool {
+        self.grid.is_empty()
+    }
+
+    /// The cell at a flat index, counting along each row in turn.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.grid.get(index)
+    }
+
+    /// The cell at a flat index, mutably.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.grid.get_mut(index)
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.grid.iter()
+    }
+
+    /// Every cell mutably, row by row.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.grid.iter_mut()
+    }
+
+    /// The flat index of column `x` of row `y`, or `None` if that's outside
+    /// the grid. The coordinates are signed so offsets can step off the edge.
+    pub fn index(&self, x: isize, y: isize) -> Option<usize> {
+        if (0..self.width as isize).contains(&x)
+            && (0..self.height as isize).contains(&y) {
+            Some((x + y * self.width as isize) as _)
+        } else {
+            None
+        }
+    }
+
+    /// The `(x, y)` coordinates of a flat index.
+    pub fn coords(&self, index: usize) -> (isize, isize) {
+        ((index % self.width) as _, (index / self.width) as _)
+    }
+
+    /// The flat indices of the neighbours of a cell that are inside the grid.
+    pub fn get_neighbor_positions(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let (x, y) = self.coords(index);
+        self.neighborhood.offsets().iter()
+            .map(move |(offset_x, offset_y)| (offset_x + x, offset_y + y))
+            .filter_map(|(x, y)| self.index(x, y))
+    }
+
+    /// The neighbours of a cell that are inside the grid.
+    pub fn get_neighbors(&self, index: usize) -> impl Iterator<Item = &T> + '_ {
+        self.get_neighbor_positions(index)
+            .filter_map(|i| self.grid.get(i))
+    }
+
+    /// Row `y`, if there is one.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.grid.get(y * self.width..(y + 1) * self.width)
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.grid.chunks(self.width)
+    }
+
+    /// The cells of column `x` top to bottom, if there is one.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T> + '_> {
+        if x < self.width {
+            Some(self.grid.iter().skip(x).step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    /// Every column, left to right, each top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.grid.iter().skip(x).step_by(self.width))
+    }
+
+    /// A grid of the same shape and neighbourhood with `f` applied to each
+    /// cell.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> FlatGrid<U> {
+        FlatGrid {
+            grid: self.grid.into_iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            neighborhood: self.neighborhood,
+        }
+    }
+}
+
+impl<T> FlatGrid<T>
+where
+    T: From<u8>
+{
+    /// Parses a block of single digit cells, one row per line.
+    pub fn from_digit_str(input: &str) -> Result<Self, ParseError> {
+        let width = input.lines().next().ok_or(ParseError::Empty)?.chars().count();
+        if width == 0 {
+            return Err(ParseError::Empty)
+        }
+
+        let mut grid = Vec::new();
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::Ragged { line: y + 1, expected: width, found: line.chars().count() })
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let digit = c.to_digit(10)
+                    .ok_or(ParseError::InvalidDigit { location: Location::new(y + 1, x + 1), found: c })?;
+                grid.push(T::from(digit as u8));
+            }
+
+            height += 1;
+        }
+
+        Ok(FlatGrid::new(grid, width, height))
+    }
+}
+
+impl<T> Index<usize> for FlatGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.grid[index]
+    }
+}
+
+impl<T> IndexMut<usize> for FlatGrid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.grid[index]
+    }
+}
+
+impl<T> Display for FlatGrid<T>
+where
+    T: Display
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 { writeln!(f)? }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(neighborhood: Neighborhood) -> FlatGrid<u8> {
+        FlatGrid::from_digit_str("123\n456\n789").unwrap().with_neighborhood(neighborhood)
+    }
+
+    fn neighbors(grid: &FlatGrid<u8>, x: isize, y: isize) -> Vec<u8> {
+        let mut found: Vec<u8> = grid.get_neighbors(grid.index(x, y).unwrap()).copied().collect();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn von_neumann_neighbors() {
+        let grid = grid(Neighborhood::VonNeumann);
+
+        assert_eq!(neighbors(&grid, 0, 0), [2, 4]);
+        assert_eq!(neighbors(&grid, 2, 2), [6, 8]);
+        assert_eq!(neighbors(&grid, 1, 0), [1, 3, 5]);
+        assert_eq!(neighbors(&grid, 0, 1), [1, 5, 7]);
+        assert_eq!(neighbors(&grid, 1, 1), [2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn moore_neighbors() {
+        let grid = grid(Neighborhood::Moore);
+
+        assert_eq!(neighbors(&grid, 0, 0), [2, 4, 5]);
+        assert_eq!(neighbors(&grid, 2, 0), [2, 5, 6]);
+        assert_eq!(neighbors(&grid, 0, 2), [4, 5, 8]);
+        assert_eq!(neighbors(&grid, 2, 2), [5, 6, 8]);
+        assert_eq!(neighbors(&grid, 1, 2), [4, 5, 6, 7, 9]);
+        assert_eq!(neighbors(&grid, 1, 1), [1, 2, 3, 4, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn custom_neighbors() {
+        let grid = grid(Neighborhood::Custom(vec![(2, 0), (0, 2), (-1, -1)]));
+
+        assert_eq!(neighbors(&grid, 0, 0), [3, 7]);
+        assert_eq!(neighbors(&grid, 2, 2), [5]);
+        assert_eq!(neighbors(&grid, 1, 1), [1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid has no columns")]
+    fn rejects_zero_width() {
+        FlatGrid::<u8>::new(vec![], 0, 3);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = FlatGrid::<u8>::from_digit_str("123\n456").unwrap();
+
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [&[1, 2, 3][..], &[4, 5, 6][..]]);
+
+        assert_eq!(grid.column(2).map(|c| c.copied().collect::<Vec<_>>()), Some(vec![3, 6]));
+        assert!(grid.column(3).is_none());
+        assert_eq!(
+            grid.columns().map(|c| c.copied().collect::<Vec<_>>()).collect::<Vec<_>>(),
+            [vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn displays_rows() {
+        let grid = FlatGrid::<u8>::from_digit_str("123\n456").unwrap();
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert_eq!(FlatGrid::<u8>::from_digit_str("12\n3"),
+            Err(ParseError::Ragged { line: 2, expected: 2, found: 1 }));
+        assert_eq!(FlatGrid::<u8>::from_digit_str("12\n3x"),
+            Err(ParseError::InvalidDigit { location: Location::new(2, 2), found: 'x' }));
+        assert_eq!(FlatGrid::<u8>::from_digit_str(""), Err(ParseError::Empty));
+        assert_eq!(FlatGrid::<u8>::from_digit_str("\n"), Err(ParseError::Empty));
+    }
+}
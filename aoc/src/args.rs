@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::trace::Level;
+
+
+/// Command line flags for a single subcommand.
+pub struct Flags(Vec<(String, Option<String>)>);
+
+impl Flags {
+    /// Parses `args`, accepting only the `switches` (no value) and `options`
+    /// (one value) that the subcommand knows about.
+    pub fn parse(
+        mut args: impl Iterator<Item = String>,
+        switches: &[&str],
+        options: &[&str],
+    ) -> Result<Self> {
+        let mut flags = Vec::new();
+
+        while let Some(arg) = args.next() {
+            if switches.contains(&arg.as_str()) {
+                flags.push((arg, None));
+            } else if options.contains(&arg.as_str()) {
+                let value = args.next().ok_or_else(|| anyhow!("{} needs a value", arg))?;
+                flags.push((arg, Some(value)));
+            } else {
+                bail!("unknown argument: {}", arg);
+            }
+        }
+
+        Ok(Flags(flags))
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.0.iter().any(|(flag, _)| flag == name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.0.iter()
+            .rev()
+            .find(|(flag, _)| flag == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    pub fn number<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static
+    {
+        self.value(name)
+            .map(|value| value.parse()
+                .with_context(|| format!("invalid value for {}: {}", name, value)))
+            .transpose()
+    }
+}
+
+
+/// Which days and parts to run, taken from `--all` or `--day`/`--part`/`--input`.
+pub struct Selection {
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub input: Option<String>,
+}
+
+impl Selection {
+    pub const SWITCHES: &'static [&'static str] = &["--all"];
+    pub const OPTIONS: &'static [&'static str] = &["--day", "--part", "--input"];
+
+    pub fn from_flags(flags: &Flags) -> Result<Self> {
+        let selection = Selection {
+            day: flags.number("--day")?,
+            part: flags.number("--part")?,
+            input: flags.value("--input").map(String::from),
+        };
+
+        match (flags.has("--all"), &selection) {
+            (true, Selection { day: None, part: None, input: None }) => {}
+            (false, Selection { day: Some(_), .. }) => {}
+            (true, _) => bail!("--all can't be combined with --day, --part or --input"),
+            (false, _) => bail!("pass --day <n> or --all"),
+        }
+
+        match selection.part {
+            None | Some(1 | 2) => Ok(selection),
+            Some(part) => bail!("part must be 1 or 2, got {}", part),
+        }
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+
+/// How `run` prints its results, taken from `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A table for reading.
+    Table,
+    /// One JSON object per part and line, for scripts.
+    Json,
+}
+
+impl Format {
+    pub const OPTIONS: &'static [&'static str] = &["--format"];
+
+    pub fn from_flags(flags: &Flags) -> Result<Self> {
+        match flags.value("--format") {
+            None | Some("table") => Ok(Format::Table),
+            Some("json") => Ok(Format::Json),
+            Some(format) => bail!("format must be table or json, got {}", format),
+        }
+    }
+}
+
+
+/// How much of the days' intermediate state to trace, taken from `-v` (the
+/// main steps) or `-vv` (everything).
+pub struct Verbosity;
+
+impl Verbosity {
+    pub const SWITCHES: &'static [&'static str] = &["-v", "-vv"];
+
+    pub fn from_flags(flags: &Flags) -> Level {
+        match (flags.has("-v"), flags.has("-vv")) {
+            (_, true) => Level::Detail,
+            (true, false) => Level::Info,
+            (false, false) => Level::Off,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+
+use crate::days::{Day, Timed};
+
+
+/// One input file and how each part did on it.
+pub struct Entry {
+    pub file: String,
+    /// Both parts' results, or why the file couldn't be read.
+    pub results: Result<[Result<Timed>; 2]>,
+}
+
+impl Entry {
+    /// Every distinct reason this file failed, in part order. A parse error
+    /// fails both parts the same way, so it only appears once.
+    pub fn errors(&self) -> Vec<String> {
+        let mut errors: Vec<String> = Vec::new();
+        match &self.results {
+            Ok(results) => {
+                for error in results.iter().filter_map(|r| r.as_ref().err()) {
+                    let error = format!("{:#}", error);
+                    if !errors.contains(&error) {
+                        errors.push(error);
+                    }
+                }
+            }
+            Err(err) => errors.push(format!("{:#}", err)),
+        }
+        errors
+    }
+}
+
+
+/// Runs both parts of `day` on every file in `dir`, in name order. One input
+/// failing doesn't stop the others.
+pub fn batch(day: &Day, dir: &Path) -> Result<Vec<Entry>> {
+    let mut paths = fs::read_dir(dir)
+        .with_context(|| format!("could not read {}", dir.display()))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+    paths.retain(|path| path.is_file());
+    paths.sort();
+
+    Ok(paths.into_iter()
+        .map(|path| Entry {
+            file: path.file_name().unwrap_or_default().to_string_lossy().into_owned(),
+            results: fs::read_to_string(&path)
+                .with_context(|| format!("could not read {}", path.display()))
+                .map(|input| [day.parts[0](&input), day.parts[1](&input)]),
+        })
+        .collect())
+}
+
+
+pub fn print_table(entries: &[Entry]) {
+    let answers = |entry: &Entry| -> [String; 2] {
+        match &entry.results {
+            Ok(results) => results.each_ref().map(|r| match r {
+                Ok(timed) => timed.answer.clone(),
+                Err(_) => "error".into(),
+            }),
+            Err(_) => ["error".into(), "error".into()],
+        }
+    };
+    let width = |header: &str, column: &dyn Fn(&Entry) -> usize| {
+        entries.iter().map(column).chain([header.len()]).max().unwrap_or_default()
+    };
+    let file_width = width("file", &|e| e.file.len());
+    let part_1_width = width("part 1", &|e| answers(e)[0].len());
+    let part_2_width = width("part 2", &|e| answers(e)[1].len());
+
+    println!(
+        "{:<file_width$}  {:<part_1_width$}  {:<part_2_width$}  {:>10}  {:>10}  {:>10}  error",
+        "file", "part 1", "part 2", "parse", "solve 1", "solve 2"
+    );
+    for entry in entries {
+        let [part_1, part_2] = answers(entry);
+        let timed: [Option<&Timed>; 2] = match &entry.results {
+            Ok(results) => results.each_ref().map(|r| r.as_ref().ok()),
+            Err(_) => [None, None],
+        };
+        // both parts parse the input, so either one's parse time will do
+        let parse = timed.iter().flatten().next()
+            .map_or("-".into(), |t| format!("{:.2?}", t.parse));
+        let [solve_1, solve_2] = timed.map(|t| t.map_or("-".into(), |t| format!("{:.2?}", t.solve)));
+
+        let line = format!(
+            "{:<file_width$}  {:<part_1_width$}  {:<part_2_width$}  {:>10}  {:>10}  {:>10}  {}",
+            entry.file, part_1, part_2, parse, solve_1, solve_2, entry.errors().join("; ")
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use crate::days;
+
+    use super::*;
+
+    #[test]
+    fn failing_inputs_dont_stop_the_batch() {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("not-an-input")).unwrap();
+        fs::write(dir.join("b.txt"), "forward 5\nsideways 3\n").unwrap();
+        fs::write(dir.join("a.txt"), "down 5\nforward 5\n").unwrap();
+        fs::write(dir.join("c.txt"), "up 2\n").unwrap();
+
+        let entries = batch(days::get(2).unwrap(), &dir).unwrap();
+        let files: Vec<_> = entries.iter().map(|e| e.file.as_str()).collect();
+        assert_eq!(files, ["a.txt", "b.txt", "c.txt"]);
+
+        let answers: Vec<Vec<String>> = entries.iter()
+            .map(|e| e.results.as_ref().unwrap().iter()
+                .map(|r| r.as_ref().map_or("error".into(), |t| t.answer.clone()))
+                .collect())
+            .collect();
+        assert_eq!(answers, [["25", "125"], ["error", "error"], ["0", "0"]]);
+        assert_eq!(entries[1].errors().len(), 1);
+    }
+}
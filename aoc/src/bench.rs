@@ -0,0 +1,111 @@
+use std::{fs, path::Path, time::Duration};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use crate::days::Day;
+
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Stats { min: samples[0], median, max: samples[samples.len() - 1] }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "min": self.min.as_nanos() as u64,
+            "median": self.median.as_nanos() as u64,
+            "max": self.max.as_nanos() as u64,
+        })
+    }
+}
+
+pub struct Bench {
+    pub day: u8,
+    pub part: u8,
+    pub iterations: usize,
+    pub answer: String,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Bench {
+    fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "iterations": self.iterations,
+            "answer": self.answer,
+            "parse_ns": self.parse.to_json(),
+            "solve_ns": self.solve.to_json(),
+        })
+    }
+}
+
+
+/// Parses and solves one part `iterations` times, keeping the parse and solve
+/// timings apart. Stops at the first error.
+pub fn bench(day: &Day, part: u8, input: &str, iterations: usize) -> Result<Bench> {
+    let solver = day.parts[part as usize - 1];
+    let mut answer = String::new();
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solve = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let timed = solver(input)?;
+        parse.push(timed.parse);
+        solve.push(timed.solve);
+        answer = timed.answer;
+    }
+
+    Ok(Bench {
+        day: day.number,
+        part,
+        iterations,
+        answer,
+        parse: Stats::from_samples(parse),
+        solve: Stats::from_samples(solve),
+    })
+}
+
+pub fn print_table(benches: &[&Bench]) {
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "day", "part", "parse min", "parse med", "parse max", "solve min", "solve med", "solve max"
+    );
+
+    for bench in benches {
+        println!(
+            "{:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+            bench.day,
+            bench.part,
+            format!("{:.2?}", bench.parse.min),
+            format!("{:.2?}", bench.parse.median),
+            format!("{:.2?}", bench.parse.max),
+            format!("{:.2?}", bench.solve.min),
+            format!("{:.2?}", bench.solve.median),
+            format!("{:.2?}", bench.solve.max),
+        );
+    }
+}
+
+pub fn write_json(benches: &[&Bench], path: &Path) -> Result<()> {
+    let out = Value::Array(benches.iter().map(|b| b.to_json()).collect());
+    let out = serde_json::to_string_pretty(&out)?;
+    fs::write(path, out + "\n")
+        .with_context(|| format!("could not write {}", path.display()))
+}
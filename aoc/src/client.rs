@@ -0,0 +1,386 @@
+//! Fetches puzzle inputs and submits answers over plain HTTP, which the
+//! stand-in in `server` speaks but the real puzzle site, being HTTPS only,
+//! doesn't.
+//!
+//! What the server says is kept in a [`Store`] so it's never asked twice:
+//! inputs are cached in `inputs/`, accepted answers go in `answers/accepted/`
+//! and are copied to `answers/` for `verify` to check, rejected answers go in
+//! `answers/wrong/`, and the earliest time another answer may be sent in
+//! `answers/.next-submission`. Answers `verify --record` saved were never
+//! judged, so they don't count as accepted.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::input;
+
+use crate::{http, verify};
+
+
+pub const YEAR: u16 = 2021;
+
+/// How long to hold off after a wrong answer, which is what the site asks.
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// What came of submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// `hint` is `too high` or `too low` when the site gives one.
+    Wrong { hint: Option<String> },
+    /// The part is already solved, or it's part 2 and part 1 isn't.
+    WrongLevel,
+    /// It's too soon after the last wrong answer to send another.
+    TooSoon { wait: Duration },
+    /// This answer was accepted before, so it wasn't sent again.
+    AlreadyCorrect,
+    /// This answer was rejected before, or another one accepted, so it
+    /// wasn't sent.
+    AlreadyWrong,
+}
+
+impl Verdict {
+    pub fn is_correct(&self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::AlreadyCorrect)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { hint: Some(hint) } => write!(f, "wrong, {}", hint),
+            Verdict::Wrong { hint: None } => write!(f, "wrong"),
+            Verdict::WrongLevel => write!(f, "not accepted, that part is already solved or still locked"),
+            Verdict::TooSoon { wait } => write!(f, "not sent, wait {}s before submitting again", wait.as_secs()),
+            Verdict::AlreadyCorrect => write!(f, "already accepted"),
+            Verdict::AlreadyWrong => write!(f, "not sent, this answer is already known to be wrong"),
+        }
+    }
+}
+
+
+/// The files the client keeps, under `root`.
+pub struct Store {
+    root: PathBuf,
+}
+
+impl Store {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Store { root: root.into() }
+    }
+
+    pub fn input(&self, day: u8) -> Result<Option<String>> {
+        read_optional(&self.root.join(input::default_path(day)))
+    }
+
+    pub fn save_input(&self, day: u8, input: &str) -> Result<()> {
+        write_creating(&self.root.join(input::default_path(day)), input)
+    }
+
+    fn judged_path(&self, verdict: &str, day: u8, part: u8) -> PathBuf {
+        self.root.join(verify::ANSWER_DIR).join(verdict).join(format!("day-{:02}-part-{}.txt", day, part))
+    }
+
+    /// The answer the server accepted, if it has.
+    pub fn accepted(&self, day: u8, part: u8) -> Result<Option<String>> {
+        Ok(read_optional(&self.judged_path("accepted", day, part))?
+            .map(|answer| answer.trim().to_string()))
+    }
+
+    /// Keeps `answer` as accepted, and as the one `verify` expects.
+    fn record_accepted(&self, day: u8, part: u8, answer: &str) -> Result<()> {
+        let answer = format!("{}\n", answer);
+        write_creating(&self.judged_path("accepted", day, part), &answer)?;
+        write_creating(&self.root.join(verify::answer_path(day, part)), &answer)
+    }
+
+    /// Answers the site rejected, oldest first.
+    pub fn wrong_answers(&self, day: u8, part: u8) -> Result<Vec<String>> {
+        Ok(read_optional(&self.judged_path("wrong", day, part))?
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .map(String::from)
+            .collect())
+    }
+
+    /// Adds `answer` to the rejected ones, along with the site's hint.
+    fn record_wrong(&self, day: u8, part: u8, answer: &str, hint: Option<&str>) -> Result<()> {
+        let path = self.judged_path("wrong", day, part);
+        let mut wrong = read_optional(&path)?.unwrap_or_default();
+        wrong += answer;
+        if let Some(hint) = hint {
+            wrong += &format!(" ({})", hint);
+        }
+        wrong += "\n";
+        write_creating(&path, &wrong)
+    }
+
+    fn next_submission_path(&self) -> PathBuf {
+        self.root.join(verify::ANSWER_DIR).join(".next-submission")
+    }
+
+    /// The earliest time another answer may be sent, if there's been a wrong
+    /// one lately.
+    pub fn next_submission(&self) -> Result<Option<SystemTime>> {
+        let Some(secs) = read_optional(&self.next_submission_path())? else { return Ok(None) };
+        let secs = secs.trim().parse().context("answers/.next-submission should hold a unix time")?;
+        Ok(Some(UNIX_EPOCH + Duration::from_secs(secs)))
+    }
+
+    fn hold_off(&self, until: SystemTime) -> Result<()> {
+        let secs = until.duration_since(UNIX_EPOCH).unwrap_or_default();
+        // round up, so the wait is never cut short
+        let secs = secs.as_secs() + u64::from(secs.subsec_nanos() > 0);
+        write_creating(&self.next_submission_path(), &format!("{}\n", secs))
+    }
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("could not read {}", path.display())),
+    }
+}
+
+fn write_creating(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("could not create {}", dir.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("could not write {}", path.display()))
+}
+
+
+pub struct Client {
+    /// `host:port` of the server.
+    address: String,
+    session: String,
+    /// How long to hold off after a wrong answer, when the server doesn't say.
+    pub cooldown: Duration,
+}
+
+impl Client {
+    /// A client for the server at `server`, which has to be a plain
+    /// `http://host:port` address, logged in with the `session` cookie.
+    pub fn new(server: &str, session: &str) -> Result<Self> {
+        if server.starts_with("https://") {
+            bail!("only plain http:// servers such as `aoc serve` are supported, not {}", server);
+        }
+        let address = server.trim_start_matches("http://").trim_end_matches('/');
+        if address.contains('/') {
+            bail!("the server address can't have a path: {}", server);
+        }
+        let address = match address.contains(':') {
+            true => address.to_string(),
+            false => format!("{}:80", address),
+        };
+
+        Ok(Client { address, session: session.to_string(), cooldown: DEFAULT_COOLDOWN })
+    }
+
+    fn send(&self, method: &str, path: String, body: String) -> Result<http::Response> {
+        let mut headers = vec![
+            ("Cookie".into(), format!("session={}", self.session)),
+            ("User-Agent".into(), "aoc-2021 runner".into()),
+        ];
+        if !body.is_empty() {
+            headers.push(("Content-Type".into(), "application/x-www-form-urlencoded".into()));
+        }
+
+        http::send(&self.address, &http::Request { method: method.into(), path, headers, body })
+    }
+
+    /// Downloads a day's input, whether or not it's cached.
+    pub fn fetch(&self, day: u8) -> Result<String> {
+        let response = self.send("GET", format!("/{}/day/{}/input", YEAR, day), String::new())?;
+        if response.status != 200 {
+            bail!(
+                "could not fetch day {}: {} {}",
+                day, response.status, response.body.lines().next().unwrap_or_default()
+            );
+        }
+        Ok(response.body)
+    }
+
+    /// A day's input from the store, fetching and caching it if it isn't there.
+    pub fn input(&self, store: &Store, day: u8) -> Result<String> {
+        if let Some(input) = store.input(day)? {
+            return Ok(input)
+        }
+
+        let input = self.fetch(day)?;
+        store.save_input(day, &input)?;
+        Ok(input)
+    }
+
+    /// Sends `answer`, unless the store already knows how that would go or
+    /// it's too soon to, and records what the server says.
+    pub fn submit(&self, store: &Store, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let answer = answer.trim();
+        match store.accepted(day, part)? {
+            Some(accepted) if accepted == answer => return Ok(Verdict::AlreadyCorrect),
+            Some(_) => return Ok(Verdict::AlreadyWrong),
+            None => {}
+        }
+        if store.wrong_answers(day, part)?.iter().any(|wrong| wrong == answer) {
+            return Ok(Verdict::AlreadyWrong)
+        }
+
+        let now = SystemTime::now();
+        if let Some(wait) = store.next_submission()?.and_then(|next| next.duration_since(now).ok()) {
+            return Ok(Verdict::TooSoon { wait })
+        }
+
+        let body = format!("level={}&answer={}", part, http::encode_form(answer));
+        let response = self.send("POST", format!("/{}/day/{}/answer", YEAR, day), body)?;
+        if response.status != 200 {
+            bail!("could not submit day {} part {}: {}", day, part, response.status);
+        }
+
+        let verdict = parse_verdict(&response.body)?;
+        match &verdict {
+            Verdict::Correct => store.record_accepted(day, part, answer)?,
+            Verdict::Wrong { hint } => {
+                store.record_wrong(day, part, answer, hint.as_deref())?;
+                if !self.cooldown.is_zero() {
+                    store.hold_off(now + self.cooldown)?;
+                }
+            }
+            Verdict::TooSoon { wait } => store.hold_off(now + *wait)?,
+            _ => {}
+        }
+        Ok(verdict)
+    }
+}
+
+/// Reads the verdict out of the site's reply to an answer.
+fn parse_verdict(body: &str) -> Result<Verdict> {
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        let hint = ["too high", "too low"].into_iter()
+            .find(|hint| body.contains(&format!("your answer is {}", hint)));
+        Ok(Verdict::Wrong { hint: hint.map(String::from) })
+    } else if body.contains("You gave an answer too recently") {
+        Ok(Verdict::TooSoon { wait: parse_wait(body).unwrap_or(DEFAULT_COOLDOWN) })
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        bail!("didn't understand the server's reply: {}", body.trim())
+    }
+}
+
+/// The wait in `You have 1m 5s left to wait`.
+fn parse_wait(body: &str) -> Option<Duration> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left")?;
+
+    wait.split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.len().checked_sub(1)?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, process, thread};
+
+    use crate::server::Server;
+
+    use super::*;
+
+    /// A fresh directory under the system's temporary one.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// A stand-in server with an input and answers for day 1, and a client
+    /// and empty store to talk to it with.
+    fn stand_in(name: &str, server_cooldown: Duration) -> (Client, Store, PathBuf) {
+        let fixtures = temp_dir(&format!("{}-fixtures", name));
+        write_creating(&fixtures.join(input::default_path(1)), "199\n200\n208\n").unwrap();
+        write_creating(&fixtures.join(verify::answer_path(1, 1)), "7\n").unwrap();
+        write_creating(&fixtures.join(verify::answer_path(1, 2)), "5\n").unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = Server::new(&fixtures, server_cooldown);
+        thread::spawn(move || server.serve(listener));
+
+        let mut client = Client::new(&format!("http://{}", address), "test").unwrap();
+        client.cooldown = Duration::ZERO;
+        (client, Store::new(temp_dir(name)), fixtures)
+    }
+
+    #[test]
+    fn inputs_are_fetched_once_and_cached() {
+        let (client, store, fixtures) = stand_in("cache", Duration::ZERO);
+
+        assert_eq!(client.input(&store, 1).unwrap(), "199\n200\n208\n");
+        fs::remove_dir_all(fixtures).unwrap();
+
+        assert_eq!(client.input(&store, 1).unwrap(), "199\n200\n208\n");
+        assert!(client.fetch(1).is_err());
+    }
+
+    #[test]
+    fn answers_are_judged_and_remembered() {
+        let (client, store, _) = stand_in("judge", Duration::ZERO);
+
+        assert_eq!(client.submit(&store, 1, 2, "5").unwrap(), Verdict::WrongLevel);
+        assert_eq!(client.submit(&store, 1, 1, "9").unwrap(), Verdict::Wrong { hint: Some("too high".into()) });
+        assert_eq!(client.submit(&store, 1, 1, "9").unwrap(), Verdict::AlreadyWrong);
+        assert_eq!(store.wrong_answers(1, 1).unwrap(), vec!["9"]);
+
+        assert_eq!(client.submit(&store, 1, 1, "7").unwrap(), Verdict::Correct);
+        assert_eq!(store.accepted(1, 1).unwrap().as_deref(), Some("7"));
+        assert_eq!(client.submit(&store, 1, 1, "7").unwrap(), Verdict::AlreadyCorrect);
+        assert_eq!(client.submit(&store, 1, 1, "6").unwrap(), Verdict::AlreadyWrong);
+    }
+
+    #[test]
+    fn recorded_answers_are_still_sent() {
+        let (client, store, _) = stand_in("recorded", Duration::ZERO);
+        write_creating(&store.root.join(verify::answer_path(1, 1)), "6\n").unwrap();
+
+        assert_eq!(store.accepted(1, 1).unwrap(), None);
+        assert_eq!(client.submit(&store, 1, 1, "7").unwrap(), Verdict::Correct);
+        assert_eq!(fs::read_to_string(store.root.join(verify::answer_path(1, 1))).unwrap(), "7\n");
+    }
+
+    #[test]
+    fn wrong_answers_hold_off_further_submissions() {
+        let (client, store, _) = stand_in("rate", Duration::from_secs(65));
+
+        // the server locks out for longer than the client expects, so the
+        // second answer is sent and turned away
+        assert!(matches!(client.submit(&store, 1, 1, "3").unwrap(), Verdict::Wrong { .. }));
+        let verdict = client.submit(&store, 1, 1, "4").unwrap();
+        assert!(matches!(verdict, Verdict::TooSoon { wait } if wait > Duration::from_secs(60)));
+
+        // and now the client knows to wait without asking
+        assert!(store.next_submission().unwrap().is_some());
+        assert!(matches!(client.submit(&store, 1, 1, "7").unwrap(), Verdict::TooSoon { .. }));
+        assert_eq!(store.wrong_answers(1, 1).unwrap(), vec!["3"]);
+    }
+}
@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use aoc_common::{rng::Rng, Solution};
+
+use crate::memory::{self, Usage};
+
+
+/// The answer to one part along with how long parsing and solving took.
+pub struct Timed {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    /// What each step allocated, when built with the `memory` feature.
+    pub parse_memory: Option<Usage>,
+    pub solve_memory: Option<Usage>,
+    /// The day's named intermediate values, worked out after the timing stops.
+    pub diagnostics: Vec<(&'static str, String)>,
+}
+
+pub type Solver = fn(&str) -> Result<Timed>;
+
+/// Builds a random input of roughly the given size from a seeded generator.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+pub struct Day {
+    pub number: u8,
+    pub parts: [Solver; 2],
+    pub generate: Generator,
+    pub default_size: usize,
+}
+
+/// Runs `S::parse` then the given part, timing and measuring each step on
+/// its own.
+fn timed<S: Solution, const PART: u8>(input: &str) -> Result<Timed> {
+    let start = Instant::now();
+    let (parsed, parse_memory) = memory::measure(|| S::parse(input));
+    let parsed = parsed?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let (answer, solve_memory) = memory::measure(|| match PART {
+        1 => S::part1(&parsed).map(|answer| answer.to_string()),
+        _ => S::part2(&parsed).map(|answer| answer.to_string()),
+    });
+    let answer = answer?;
+    let solve = start.elapsed();
+
+    let diagnostics = S::diagnostics(&parsed, PART);
+    Ok(Timed { answer, parse, solve, parse_memory, solve_memory, diagnostics })
+}
+
+/// Everything the runner needs to know about one day.
+const fn day<S: Solution>(generate: Generator, default_size: usize) -> Day {
+    Day {
+        number: S::DAY,
+        parts: [timed::<S, 1>, timed::<S, 2>],
+        generate,
+        default_size,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<aoc_1::Puzzle>(aoc_1::generate::generate, aoc_1::generate::DEFAULT_SIZE),
+    day::<aoc_2::Puzzle>(aoc_2::generate::generate, aoc_2::generate::DEFAULT_SIZE),
+    day::<aoc_3::Puzzle>(aoc_3::generate::generate, aoc_3::generate::DEFAULT_SIZE),
+    day::<aoc_4::Puzzle>(aoc_4::generate::generate, aoc_4::generate::DEFAULT_SIZE),
+    day::<aoc_5::Puzzle>(aoc_5::generate::generate, aoc_5::generate::DEFAULT_SIZE),
+    day::<aoc_6::Puzzle>(aoc_6::generate::generate, aoc_6::generate::DEFAULT_SIZE),
+    day::<aoc_7::Puzzle>(aoc_7::generate::generate, aoc_7::generate::DEFAULT_SIZE),
+    day::<aoc_8::Puzzle>(aoc_8::generate::generate, aoc_8::generate::DEFAULT_SIZE),
+    day::<aoc_9::Puzzle>(aoc_9::generate::generate, aoc_9::generate::DEFAULT_SIZE),
+    day::<aoc_10::Puzzle>(aoc_10::generate::generate, aoc_10::generate::DEFAULT_SIZE),
+    day::<aoc_11::Puzzle>(aoc_11::generate::generate, aoc_11::generate::DEFAULT_SIZE),
+    day::<aoc_12::Puzzle>(aoc_12::generate::generate, aoc_12::generate::DEFAULT_SIZE),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
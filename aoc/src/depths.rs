@@ -0,0 +1,116 @@
+//! Printing day 1's depth-log report and anomalies.
+
+use aoc_1::{anomaly::{Anomaly, Threshold}, report::Report};
+use serde_json::{json, Value};
+
+
+/// The widest a histogram bar gets.
+const BAR_WIDTH: usize = 50;
+
+/// How wide to wrap the list of increases.
+const LINE_WIDTH: usize = 80;
+
+/// The changes a histogram bucket covers, such as `-10..-1`.
+fn bucket_range(report: &Report, start: i64) -> String {
+    match report.bucket.get() {
+        1 => start.to_string(),
+        width => format!("{}..{}", start, start + width as i64 - 1),
+    }
+}
+
+pub fn print_table(report: &Report) {
+    println!("{:<14}  {}", "readings", report.readings);
+    match report.longest_rise {
+        Some(rise) => println!(
+            "{:<14}  {} readings, lines {} to {}",
+            "longest rise", rise.readings, rise.start, rise.end
+        ),
+        None => println!("{:<14}  -", "longest rise"),
+    }
+    match report.largest_drop {
+        Some(fall) => println!(
+            "{:<14}  {}, line {} ({} to {})",
+            "largest drop", fall.size(), fall.line, fall.from, fall.to
+        ),
+        None => println!("{:<14}  -", "largest drop"),
+    }
+    println!("{:<14}  {}", "increases", report.increases.len());
+
+    let most = report.histogram.values().copied().max().unwrap_or_default();
+    let ranges: Vec<String> = report.histogram.keys().map(|&start| bucket_range(report, start)).collect();
+    let range_width = ranges.iter().map(String::len).chain(["change".len()]).max().unwrap_or_default();
+    println!();
+    println!("{:>range_width$}  {:>6}", "change", "steps");
+    for (range, count) in ranges.iter().zip(report.histogram.values()) {
+        // every non-empty bucket gets at least one mark
+        let bar = (count * BAR_WIDTH).div_ceil(most.max(1));
+        println!("{:>range_width$}  {:>6}  {}", range, count, "#".repeat(bar));
+    }
+
+    println!();
+    println!("deeper than the reading before on lines:");
+    let mut line = String::new();
+    for increase in &report.increases {
+        let number = increase.to_string();
+        if !line.is_empty() && line.len() + 1 + number.len() > LINE_WIDTH {
+            println!("{}", line);
+            line.clear();
+        }
+        if !line.is_empty() {
+            line += " ";
+        }
+        line += &number;
+    }
+    if !line.is_empty() {
+        println!("{}", line);
+    }
+}
+
+pub fn to_json(report: &Report) -> Value {
+    json!({
+        "readings": report.readings,
+        "longest_rise": report.longest_rise.map(|rise| json!({
+            "start": rise.start,
+            "end": rise.end,
+            "readings": rise.readings,
+        })),
+        "largest_drop": report.largest_drop.map(|fall| json!({
+            "line": fall.line,
+            "from": fall.from,
+            "to": fall.to,
+            "size": fall.size(),
+        })),
+        "histogram": report.histogram.iter()
+            .map(|(&start, &count)| json!({
+                "from": start,
+                "to": start + report.bucket.get() as i64 - 1,
+                "steps": count,
+            }))
+            .collect::<Vec<_>>(),
+        "increases": report.increases,
+    })
+}
+
+
+pub fn print_anomalies(anomalies: &[Anomaly], readings: usize, window: usize, threshold: Threshold) {
+    println!("{:>6}  {:>12}  {:>12}  {:>10}", "line", "depth", "expected", "deviation");
+    for anomaly in anomalies {
+        println!(
+            "{:>6}  {:>12}  {:>12.2}  {:>+10.2}",
+            anomaly.line, anomaly.depth, anomaly.expected, anomaly.deviation
+        );
+    }
+    println!(
+        "{} of {} readings more than {} from the average of the {} before",
+        anomalies.len(), readings, threshold, window
+    );
+}
+
+pub fn anomaly_json(anomaly: &Anomaly) -> Value {
+    json!({
+        "line": anomaly.line,
+        "depth": anomaly.depth,
+        "expected": anomaly.expected,
+        "deviation": anomaly.deviation,
+    })
+}
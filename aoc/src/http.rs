@@ -0,0 +1,205 @@
+//! Just enough HTTP/1.0 for the puzzle client and the stand-in server to talk
+//! to each other: one request per connection, bodies sized by
+//! `Content-Length` or the connection closing.
+
+use std::{io::{BufRead, BufReader, Read, Write}, net::TcpStream, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+
+/// How long to wait on a silent server before giving up.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The biggest body either side will read, well above any puzzle input.
+const MAX_BODY: u64 = 16 << 20;
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+
+    /// The value of `name` in a `application/x-www-form-urlencoded` body.
+    pub fn form_value(&self, name: &str) -> Option<String> {
+        self.body.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| decode_form(value))
+    }
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Reads the header lines up to the blank line that ends them.
+fn read_headers(reader: &mut impl BufRead) -> Result<Vec<(String, String)>> {
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            return Ok(headers)
+        }
+
+        let (name, value) = line.split_once(':').ok_or_else(|| anyhow!("malformed header {:?}", line))?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+}
+
+/// Reads a body of `Content-Length` bytes, or everything up to the end of the
+/// stream if there isn't one. Bodies over [`MAX_BODY`] are refused.
+fn read_body(reader: &mut impl BufRead, headers: &[(String, String)]) -> Result<String> {
+    let mut body = Vec::new();
+    match header(headers, "Content-Length") {
+        Some(length) => {
+            let length: u64 = length.parse().with_context(|| format!("bad Content-Length {:?}", length))?;
+            if length > MAX_BODY {
+                bail!("body of {} bytes is over the {} byte limit", length, MAX_BODY);
+            }
+            reader.take(length).read_to_end(&mut body)?;
+            if body.len() as u64 != length {
+                bail!("body ended after {} of {} bytes", body.len(), length);
+            }
+        }
+        None => {
+            reader.take(MAX_BODY + 1).read_to_end(&mut body)?;
+            if body.len() as u64 > MAX_BODY {
+                bail!("body is over the {} byte limit", MAX_BODY);
+            }
+        }
+    }
+    String::from_utf8(body).context("body isn't UTF-8")
+}
+
+
+/// Sends one request to `address` (`host:port`) and reads the whole response.
+pub fn send(address: &str, request: &Request) -> Result<Response> {
+    let mut stream = TcpStream::connect(address)
+        .with_context(|| format!("could not connect to {}", address))?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+
+    let host = address.rsplit_once(':').map_or(address, |(host, _)| host);
+    let mut head = format!("{} {} HTTP/1.0\r\nHost: {}\r\n", request.method, request.path, host);
+    for (name, value) in &request.headers {
+        head += &format!("{}: {}\r\n", name, value);
+    }
+    head += &format!("Content-Length: {}\r\n\r\n", request.body.len());
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(request.body.as_bytes())?;
+
+    let mut reader = BufReader::new(stream);
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+    let status = status_line.split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| anyhow!("malformed status line {:?}", status_line.trim_end()))?;
+
+    let headers = read_headers(&mut reader)?;
+    if header(&headers, "Transfer-Encoding").is_some() {
+        bail!("the server sent a chunked response, which isn't supported");
+    }
+    let body = read_body(&mut reader, &headers)?;
+
+    Ok(Response { status, body })
+}
+
+/// Reads one request from a client.
+pub fn receive(stream: &TcpStream) -> Result<Request> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut words = request_line.split_whitespace();
+    let (method, path) = words.next().zip(words.next())
+        .ok_or_else(|| anyhow!("malformed request line {:?}", request_line.trim_end()))?;
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let headers = read_headers(&mut reader)?;
+    // a request without a length has no body, rather than one that runs to
+    // the end of a stream the client is still waiting on
+    let body = match header(&headers, "Content-Length") {
+        Some(_) => read_body(&mut reader, &headers)?,
+        None => String::new(),
+    };
+
+    Ok(Request { method, path, headers, body })
+}
+
+pub fn respond(mut stream: &TcpStream, status: u16, body: &str) -> Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.0 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\r\n{}",
+        status, reason, body.len(), body
+    )?;
+    Ok(())
+}
+
+
+/// Encodes `value` for a form body, leaving only letters, digits and `-_.~`
+/// as they are.
+pub fn encode_form(value: &str) -> String {
+    value.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            b' ' => "+".to_string(),
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+pub fn decode_form(value: &str) -> String {
+    let mut out = Vec::new();
+    let mut bytes = value.bytes();
+    while let Some(b) = bytes.next() {
+        match b {
+            b'+' => out.push(b' '),
+            b'%' => {
+                let hex: String = bytes.by_ref().take(2).map(char::from).collect();
+                out.push(u8::from_str_radix(&hex, 16).unwrap_or(b'?'));
+            }
+            b => out.push(b),
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn length(n: &str) -> Vec<(String, String)> {
+        vec![("Content-Length".into(), n.into())]
+    }
+
+    #[test]
+    fn bodies_are_read_to_their_length() {
+        assert_eq!(read_body(&mut &b"abcdef"[..], &length("3")).unwrap(), "abc");
+        assert_eq!(read_body(&mut &b"abcdef"[..], &[]).unwrap(), "abcdef");
+        assert!(read_body(&mut &b"ab"[..], &length("3")).is_err());
+        assert!(read_body(&mut &b"ab"[..], &length(&(MAX_BODY + 1).to_string())).is_err());
+    }
+}
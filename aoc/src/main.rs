@@ -0,0 +1,659 @@
+use std::{env, fs, net::TcpListener, num::NonZeroU32, path::Path, thread, time::{Duration, Instant}};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_1::{anomaly::Threshold, DepthLog};
+use aoc_common::{input::{self, Source}, rng::Rng, trace::{self, Event}};
+use serde_json::{json, Map, Value};
+
+mod args;
+mod batch;
+mod bench;
+mod client;
+mod days;
+mod depths;
+mod http;
+mod memory;
+mod parallel;
+mod scaffold;
+mod server;
+mod verify;
+
+use args::{Flags, Format, Selection, Verbosity};
+use client::{Client, Store};
+use days::{Day, Timed};
+use server::Server;
+
+
+const USAGE: &str = "\
+usage: aoc run (--day <n> [--part <1|2>] [--input <path|->] | --all) [--format <table|json>] [-v|-vv]
+               [--parallel [--jobs <n>]]
+       aoc bench (--day <n> [--part <1|2>] [--input <path|->] | --all)
+                 [--iterations <n>] [--json <path>]
+       aoc verify (--day <n> [--part <1|2>] | --all) [--record]
+       aoc batch --day <n> --dir <path>
+       aoc sonar [--input <path|->] [--windows <n,n,...>] [--every <n>]
+       aoc depths [--input <path|->] [--bucket <n>] [--format <table|json>] [--strict]
+       aoc anomalies [--input <path|->] [--window <n>] [--threshold <depth> | --sigmas <n>]
+                     [--format <table|json>] [--strict]
+       aoc generate --day <n> [--seed <n>] [--size <n>] [--output <path>]
+       aoc fetch (--day <n> | --all) [--force] [--server <url>]
+       aoc submit --day <n> --part <1|2> [--answer <answer>] [--server <url>]
+       aoc serve [--fixtures <dir>] [--port <n>] [--cooldown <seconds>]
+       aoc new-day --day <n>
+
+Inputs are read from inputs/day-NN.txt unless --input is given. verify compares the
+answers for those inputs with answers/day-NN-part-P.txt, and --record saves any that are
+missing. generate
+writes a random input for a day to stdout, or to --output. batch runs both parts of a day on
+every file in --dir and tabulates the answers, timings and errors. sonar streams a day 1
+depth log of any length, counting the increases for each window length, and prints the
+totals so far to stderr every --every readings. depths reports on a day 1 depth log: its longest
+rise, largest drop, a histogram of the changes in buckets --bucket wide and every increase.
+anomalies flags readings that differ from the average of the --window readings before them by
+more than --threshold, or by more than --sigmas standard deviations (3 by default). Both skip
+lines that aren't readings with a warning, or stop at the first with --strict. run --format json prints
+one object per part and line, with the day's intermediate values under diagnostics. -v
+traces the main steps of each solution to stderr and -vv traces everything. --parallel solves
+the days at the same time on --jobs threads (one per CPU by default) and reports the CPU time
+each day took.
+
+fetch downloads inputs into inputs/, and submit solves a part (fetching its input if
+needed) and sends the answer, or --answer if given. Both talk to --server or $AOC_SERVER,
+logged in with $AOC_SESSION. Only plain http:// servers are supported, so they work with
+the stand-in serve runs but not the real site. serve hands out the inputs and judges
+answers found under --fixtures.
+
+new-day creates an aoc-N crate for a day from the template, run from the root of the
+workspace, and adds it to the workspace and the runner.";
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+const DEFAULT_PORT: u16 = 2021;
+
+/// How many readings `anomalies` averages, as in day 1 part 2.
+const DEFAULT_WINDOW: usize = 3;
+
+const DEFAULT_SIGMAS: f64 = 3.0;
+
+/// A day to run with its input loaded and the parts picked out.
+struct Job {
+    day: &'static Day,
+    parts: Vec<u8>,
+    input: String,
+}
+
+struct Run {
+    day: u8,
+    part: u8,
+    result: Result<Timed>,
+}
+
+/// One day's runs from `run --parallel`, with how long the day took.
+struct DayReport {
+    day: u8,
+    runs: Vec<Run>,
+    wall: Duration,
+    /// The CPU time its thread spent on it, where the platform says.
+    cpu: Option<Duration>,
+}
+
+/// A part that returned an error instead of an answer.
+struct Failure<'a> {
+    day: u8,
+    part: u8,
+    error: &'a anyhow::Error,
+}
+
+
+fn load_input(day: &Day, path: Option<&str>) -> Result<String> {
+    let source = Source::from_arg(day.number, path);
+    Ok(input::load(&source)?)
+}
+
+fn selected_days(selection: &Selection) -> Result<Vec<&'static Day>> {
+    match selection.day {
+        Some(number) => Ok(vec![days::get(number)
+            .ok_or_else(|| anyhow!("no solution for day {}", number))?]),
+        None => Ok(days::DAYS.iter().collect()),
+    }
+}
+
+fn jobs(selection: &Selection) -> Result<Vec<Job>> {
+    selected_days(selection)?.into_iter()
+        .map(|day| Ok(Job {
+            day,
+            parts: selection.parts(),
+            input: load_input(day, selection.input.as_deref())?,
+        }))
+        .collect()
+}
+
+
+fn run(jobs: &[Job]) -> Vec<Run> {
+    jobs.iter()
+        .flat_map(|job| job.parts.iter().map(move |&part| Run {
+            day: job.day.number,
+            part,
+            result: job.day.parts[part as usize - 1](&job.input),
+        }))
+        .collect()
+}
+
+/// Runs each selected day on a pool of `threads` threads. Anything going
+/// wrong with a day, from a missing input to a panicking solver, only fails
+/// that day.
+fn run_parallel(selection: &Selection, threads: usize) -> Result<Vec<DayReport>> {
+    let days = selected_days(selection)?;
+
+    Ok(parallel::map(&days, threads, |day| {
+        let start = Instant::now();
+        let cpu_start = parallel::thread_cpu_time();
+
+        let input = load_input(day, selection.input.as_deref());
+        let runs = selection.parts().into_iter()
+            .map(|part| Run {
+                day: day.number,
+                part,
+                result: match &input {
+                    Ok(input) => parallel::isolate(|| day.parts[part as usize - 1](input)),
+                    Err(err) => Err(anyhow!("{:#}", err)),
+                },
+            })
+            .collect();
+
+        let cpu = cpu_start.zip(parallel::thread_cpu_time()).map(|(start, end)| end - start);
+        DayReport { day: day.number, runs, wall: start.elapsed(), cpu }
+    }))
+}
+
+fn print_table(runs: &[Run]) {
+    let answer_width = runs.iter()
+        .filter_map(|r| r.result.as_ref().ok())
+        .map(|t| t.answer.len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    // with the `memory` feature, what each step allocated follows its time
+    let memory = |usage: Option<memory::Usage>| usage.map(|u| u.to_string());
+    let memory_width = runs.iter()
+        .filter_map(|r| r.result.as_ref().ok())
+        .flat_map(|t| [memory(t.parse_memory), memory(t.solve_memory)])
+        .flatten()
+        .map(|m| m.len())
+        .chain(["parse memory".len()])
+        .max()
+        .unwrap_or_default();
+    let memory_columns = |parse: &str, solve: &str| match cfg!(feature = "memory") {
+        true => format!("  {:<memory_width$}  {}", parse, solve),
+        false => String::new(),
+    };
+
+    println!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>10}  {:>10}{}",
+        "day", "part", "answer", "parse", "solve", memory_columns("parse memory", "solve memory")
+    );
+    for run in runs {
+        let (answer, parse, solve, parse_memory, solve_memory) = match &run.result {
+            Ok(timed) => (
+                timed.answer.clone(),
+                format!("{:.2?}", timed.parse),
+                format!("{:.2?}", timed.solve),
+                memory(timed.parse_memory).unwrap_or_default(),
+                memory(timed.solve_memory).unwrap_or_default(),
+            ),
+            Err(_) => ("error".into(), "-".into(), "-".into(), "-".into(), "-".into()),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10}  {:>10}{}",
+            run.day, run.part, answer, parse, solve, memory_columns(&parse_memory, &solve_memory)
+        );
+    }
+}
+
+/// Prints a row per day with both answers and the time it took, then the
+/// totals. With the `memory` feature each row also has what parsing and each
+/// part's solve allocated.
+fn print_report(reports: &[DayReport], threads: usize, wall: Duration) {
+    let answer = |report: &DayReport, part: u8| match report.runs.iter().find(|r| r.part == part) {
+        Some(Run { result: Ok(timed), .. }) => timed.answer.clone(),
+        Some(Run { result: Err(_), .. }) => "error".into(),
+        None => "-".into(),
+    };
+    let width = |part: u8| reports.iter()
+        .map(|r| answer(r, part).len())
+        .chain(["part 1".len()])
+        .max()
+        .unwrap_or_default();
+    let (width_1, width_2) = (width(1), width(2));
+
+    // both parts parse the same input, so the first one's parse stands for the day
+    let memory = |usage: Option<memory::Usage>| usage.map_or("-".into(), |u| u.to_string());
+    let parse_memory = |report: &DayReport| memory(report.runs.iter()
+        .find_map(|r| r.result.as_ref().ok())
+        .and_then(|t| t.parse_memory));
+    let solve_memory = |report: &DayReport, part: u8| memory(report.runs.iter()
+        .find(|r| r.part == part)
+        .and_then(|r| r.result.as_ref().ok())
+        .and_then(|t| t.solve_memory));
+    let memory_width = reports.iter()
+        .flat_map(|r| [parse_memory(r), solve_memory(r, 1), solve_memory(r, 2)])
+        .map(|m| m.len())
+        .chain(["part 1 memory".len()])
+        .max()
+        .unwrap_or_default();
+    let memory_columns = |parse: &str, part_1: &str, part_2: &str| match cfg!(feature = "memory") {
+        true => format!("  {:<memory_width$}  {:<memory_width$}  {}", parse, part_1, part_2),
+        false => String::new(),
+    };
+
+    println!(
+        "{:>3}  {:<width_1$}  {:<width_2$}  {:>10}  {:>10}{}",
+        "day", "part 1", "part 2", "cpu", "wall", memory_columns("parse memory", "part 1 memory", "part 2 memory")
+    );
+    for report in reports {
+        println!(
+            "{:>3}  {:<width_1$}  {:<width_2$}  {:>10}  {:>10}{}",
+            report.day,
+            answer(report, 1),
+            answer(report, 2),
+            report.cpu.map_or("-".into(), |cpu| format!("{:.2?}", cpu)),
+            format!("{:.2?}", report.wall),
+            memory_columns(&parse_memory(report), &solve_memory(report, 1), &solve_memory(report, 2)),
+        );
+    }
+
+    let cpu = reports.iter()
+        .map(|r| r.cpu)
+        .sum::<Option<Duration>>()
+        .map_or(String::new(), |cpu| format!(", {:.2?} of CPU time", cpu));
+    let failed = reports.iter()
+        .filter(|r| r.runs.iter().any(|run| run.result.is_err()))
+        .count();
+    println!(
+        "{} on {} in {:.2?}{}, {} failed",
+        count(reports.len(), "day"), count(threads.min(reports.len()), "thread"), wall, cpu, failed
+    );
+}
+
+/// `1 day`, `2 days` and so on.
+fn count(n: usize, noun: &str) -> String {
+    match n {
+        1 => format!("1 {}", noun),
+        n => format!("{} {}s", n, noun),
+    }
+}
+
+/// Prints each run as a JSON object on its own line. Failed parts have a null
+/// answer and timings, and say why under `error`.
+fn print_json(runs: &[Run]) {
+    for run in runs {
+        let out = match &run.result {
+            Ok(timed) => json!({
+                "day": run.day,
+                "part": run.part,
+                "answer": timed.answer,
+                "parse_ns": timed.parse.as_nanos() as u64,
+                "solve_ns": timed.solve.as_nanos() as u64,
+                "parse_memory": timed.parse_memory.map(memory_json),
+                "solve_memory": timed.solve_memory.map(memory_json),
+                "diagnostics": timed.diagnostics.iter()
+                    .map(|(name, value)| (name.to_string(), Value::from(value.as_str())))
+                    .collect::<Map<_, _>>(),
+                "error": null,
+            }),
+            Err(err) => json!({
+                "day": run.day,
+                "part": run.part,
+                "answer": null,
+                "parse_ns": null,
+                "solve_ns": null,
+                "parse_memory": null,
+                "solve_memory": null,
+                "diagnostics": {},
+                "error": format!("{:#}", err),
+            }),
+        };
+
+        println!("{}", out);
+    }
+}
+
+fn memory_json(usage: memory::Usage) -> Value {
+    json!({
+        "peak_bytes": usage.peak,
+        "allocated_bytes": usage.allocated,
+        "allocations": usage.allocations,
+    })
+}
+
+/// Prints a trace event to stderr as a JSON object, for `run --format json`.
+fn print_event_json(event: &Event) {
+    let mut out = Map::new();
+    out.insert("trace".into(), Value::from(event.level.to_string()));
+    out.insert("day".into(), Value::from(event.day));
+    out.insert("event".into(), Value::from(event.name));
+    for (name, value) in &event.fields {
+        out.insert(name.to_string(), Value::from(value.as_str()));
+    }
+    eprintln!("{}", Value::Object(out));
+}
+
+/// Prints each failure to stderr, and turns any failures into an error so the
+/// exit status reflects them.
+fn report_failures(failures: &[Failure]) -> Result<()> {
+    for failure in failures {
+        eprintln!("day {} part {}: {:#}", failure.day, failure.part, failure.error);
+    }
+
+    match failures.len() {
+        0 => Ok(()),
+        1 => bail!("1 part failed"),
+        n => bail!("{} parts failed", n),
+    }
+}
+
+
+/// The day 1 depth log named by `--input`. Lines that aren't readings are
+/// skipped with a warning, or are an error with `--strict`.
+fn depth_log(flags: &Flags) -> Result<DepthLog> {
+    let input = input::load(&Source::from_arg(1, flags.value("--input")))?;
+    let log = aoc_1::parse_log(&input);
+    if let Some(err) = log.malformed.first().filter(|_| flags.has("--strict")) {
+        bail!("{}", err);
+    }
+    for err in &log.malformed {
+        eprintln!("skipping {}", err);
+    }
+    Ok(log)
+}
+
+
+/// A client for `--server` or `$AOC_SERVER`, logged in with `$AOC_SESSION`.
+fn client(flags: &Flags) -> Result<Client> {
+    let server = flags.value("--server").map(String::from)
+        .or_else(|| env::var("AOC_SERVER").ok())
+        .ok_or_else(|| anyhow!("pass --server <url> or set AOC_SERVER"))?;
+    let session = env::var("AOC_SESSION")
+        .map_err(|_| anyhow!("set AOC_SESSION to the session cookie to log in with"))?;
+
+    Client::new(&server, &session)
+}
+
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("run") => {
+            let switches = [Selection::SWITCHES, Verbosity::SWITCHES, &["--parallel"]].concat();
+            let options = [Selection::OPTIONS, Format::OPTIONS, &["--jobs"]].concat();
+            let flags = Flags::parse(args, &switches, &options)?;
+            let format = Format::from_flags(&flags)?;
+            trace::set_level(Verbosity::from_flags(&flags));
+            if format == Format::Json {
+                trace::set_sink(print_event_json);
+            }
+            let selection = Selection::from_flags(&flags)?;
+
+            let runs = match flags.has("--parallel") {
+                true => {
+                    if format == Format::Json {
+                        bail!("--parallel only prints a table");
+                    }
+                    let threads = match flags.number("--jobs")? {
+                        Some(0) => bail!("--jobs must be at least 1"),
+                        Some(threads) => threads,
+                        None => thread::available_parallelism().map_or(1, |n| n.get()),
+                    };
+
+                    let start = Instant::now();
+                    let reports = run_parallel(&selection, threads)?;
+                    print_report(&reports, threads, start.elapsed());
+                    reports.into_iter().flat_map(|r| r.runs).collect()
+                }
+                false => {
+                    if flags.has("--jobs") {
+                        bail!("--jobs only applies with --parallel");
+                    }
+                    let runs = run(&jobs(&selection)?);
+                    match format {
+                        Format::Table => print_table(&runs),
+                        Format::Json => print_json(&runs),
+                    }
+                    runs
+                }
+            };
+
+            let failures: Vec<_> = runs.iter()
+                .filter_map(|r| r.result.as_ref().err()
+                    .map(|error| Failure { day: r.day, part: r.part, error }))
+                .collect();
+            report_failures(&failures)
+        }
+        Some("bench") => {
+            let options = [Selection::OPTIONS, &["--iterations", "--json"]].concat();
+            let flags = Flags::parse(args, Selection::SWITCHES, &options)?;
+            let jobs = jobs(&Selection::from_flags(&flags)?)?;
+            let iterations = flags.number("--iterations")?.unwrap_or(DEFAULT_ITERATIONS);
+            if iterations == 0 {
+                bail!("--iterations must be at least 1");
+            }
+
+            let results: Vec<_> = jobs.iter()
+                .flat_map(|job| job.parts.iter()
+                    .map(|&part| (job.day.number, part, bench::bench(job.day, part, &job.input, iterations))))
+                .collect();
+            let (benches, failures): (Vec<_>, Vec<_>) = results.iter()
+                .partition(|(_, _, result)| result.is_ok());
+            let benches: Vec<_> = benches.into_iter()
+                .filter_map(|(_, _, result)| result.as_ref().ok())
+                .collect();
+            let failures: Vec<_> = failures.into_iter()
+                .filter_map(|(day, part, result)| result.as_ref().err()
+                    .map(|error| Failure { day: *day, part: *part, error }))
+                .collect();
+
+            bench::print_table(&benches);
+            if let Some(path) = flags.value("--json") {
+                bench::write_json(&benches, Path::new(path))?;
+            }
+            report_failures(&failures)
+        }
+        Some("verify") => {
+            // the recorded answers are for the real inputs, so no --input
+            let switches = [Selection::SWITCHES, &["--record"]].concat();
+            let flags = Flags::parse(args, &switches, &["--day", "--part"])?;
+            let jobs = jobs(&Selection::from_flags(&flags)?)?;
+
+            let checks: Vec<_> = jobs.iter()
+                .flat_map(|job| job.parts.iter()
+                    .map(|&part| verify::check(job.day, part, &job.input)))
+                .collect();
+            verify::print_table(&checks);
+
+            if flags.has("--record") {
+                for check in &checks {
+                    if let (verify::Outcome::New, Some(answer)) = (&check.outcome, &check.answer) {
+                        verify::record(check.day, check.part, answer)?;
+                        println!("recorded day {} part {}: {}", check.day, check.part, answer);
+                    }
+                }
+            }
+
+            match checks.iter().filter(|c| c.is_failure()).count() {
+                0 => Ok(()),
+                1 => bail!("1 part failed verification"),
+                n => bail!("{} parts failed verification", n),
+            }
+        }
+        Some("batch") => {
+            let flags = Flags::parse(args, &[], &["--day", "--dir"])?;
+            let number = flags.number("--day")?.ok_or_else(|| anyhow!("pass --day <n>"))?;
+            let day = days::get(number).ok_or_else(|| anyhow!("no solution for day {}", number))?;
+            let dir = flags.value("--dir").ok_or_else(|| anyhow!("pass --dir <path>"))?;
+
+            let entries = batch::batch(day, Path::new(dir))?;
+            batch::print_table(&entries);
+
+            match entries.iter().filter(|e| !e.errors().is_empty()).count() {
+                0 => Ok(()),
+                1 => bail!("1 of {} inputs failed", entries.len()),
+                n => bail!("{} of {} inputs failed", n, entries.len()),
+            }
+        }
+        Some("sonar") => {
+            let flags = Flags::parse(args, &[], &["--input", "--windows", "--every"])?;
+            let windows = flags.value("--windows").unwrap_or("1,3")
+                .split(',')
+                .map(|w| w.trim().parse().with_context(|| format!("invalid window length {:?}", w)))
+                .collect::<Result<Vec<usize>>>()?;
+            let every = flags.number("--every")?.unwrap_or(0);
+
+            let reader = input::open(&Source::from_arg(1, flags.value("--input")))?;
+            let totals = aoc_1::stream::scan(reader, &windows, every, |totals| eprintln!("{}", totals))?;
+
+            println!("{} readings", totals.readings);
+            println!("{:>6}  increases", "window");
+            for (window, count) in &totals.increases {
+                println!("{:>6}  {}", window, count);
+            }
+            Ok(())
+        }
+        Some("depths") => {
+            let flags = Flags::parse(args, &["--strict"], &[&["--input", "--bucket"], Format::OPTIONS].concat())?;
+            let format = Format::from_flags(&flags)?;
+            let bucket = flags.number("--bucket")?.unwrap_or(1);
+            let bucket = NonZeroU32::new(bucket).ok_or_else(|| anyhow!("--bucket must be at least 1"))?;
+
+            let log = depth_log(&flags)?;
+            let report = aoc_1::report::report(&log.readings, bucket);
+            match format {
+                Format::Table => depths::print_table(&report),
+                Format::Json => println!("{}", depths::to_json(&report)),
+            }
+            Ok(())
+        }
+        Some("anomalies") => {
+            let options = [&["--input", "--window", "--threshold", "--sigmas"], Format::OPTIONS].concat();
+            let flags = Flags::parse(args, &["--strict"], &options)?;
+            let format = Format::from_flags(&flags)?;
+            let window = flags.number("--window")?.unwrap_or(DEFAULT_WINDOW);
+            if window == 0 {
+                bail!("--window must be at least 1");
+            }
+            let threshold = match (flags.number("--threshold")?, flags.number("--sigmas")?) {
+                (Some(distance), None) => Threshold::Absolute(distance),
+                (None, Some(sigmas)) => Threshold::Deviations(sigmas),
+                (None, None) => Threshold::Deviations(DEFAULT_SIGMAS),
+                (Some(_), Some(_)) => bail!("pass one of --threshold or --sigmas"),
+            };
+            let (Threshold::Absolute(limit) | Threshold::Deviations(limit)) = threshold;
+            if !(limit >= 0.0 && limit.is_finite()) {
+                bail!("the threshold must be a number of at least 0, got {}", limit);
+            }
+
+            let log = depth_log(&flags)?;
+            let anomalies = aoc_1::anomaly::anomalies(&log.readings, window, threshold);
+            match format {
+                Format::Table => depths::print_anomalies(&anomalies, log.readings.len(), window, threshold),
+                Format::Json => for anomaly in &anomalies {
+                    println!("{}", depths::anomaly_json(anomaly));
+                },
+            }
+            Ok(())
+        }
+        Some("generate") => {
+            let flags = Flags::parse(args, &[], &["--day", "--seed", "--size", "--output"])?;
+            let number = flags.number("--day")?.ok_or_else(|| anyhow!("pass --day <n>"))?;
+            let day = days::get(number).ok_or_else(|| anyhow!("no solution for day {}", number))?;
+            let seed = flags.number("--seed")?.unwrap_or(0);
+            let size = flags.number("--size")?.unwrap_or(day.default_size);
+            if size == 0 {
+                bail!("--size must be at least 1");
+            }
+
+            let input = (day.generate)(&mut Rng::new(seed), size);
+            match flags.value("--output") {
+                Some(path) => fs::write(path, input + "\n")
+                    .with_context(|| format!("could not write {}", path)),
+                None => {
+                    println!("{}", input);
+                    Ok(())
+                }
+            }
+        }
+        Some("fetch") => {
+            let flags = Flags::parse(args, &["--all", "--force"], &["--day", "--server"])?;
+            let days: Vec<u8> = match (flags.number("--day")?, flags.has("--all")) {
+                (Some(day), false) => vec![day],
+                (None, true) => days::DAYS.iter().map(|d| d.number).collect(),
+                _ => bail!("pass --day <n> or --all"),
+            };
+            let client = client(&flags)?;
+            let store = Store::new(".");
+
+            for day in days {
+                let path = input::default_path(day);
+                if !flags.has("--force") && store.input(day)?.is_some() {
+                    println!("day {}: already in {}", day, path.display());
+                    continue
+                }
+                store.save_input(day, &client.fetch(day)?)?;
+                println!("day {}: saved to {}", day, path.display());
+            }
+            Ok(())
+        }
+        Some("submit") => {
+            let flags = Flags::parse(args, &[], &["--day", "--part", "--answer", "--server"])?;
+            let number = flags.number("--day")?.ok_or_else(|| anyhow!("pass --day <n>"))?;
+            let day = days::get(number).ok_or_else(|| anyhow!("no solution for day {}", number))?;
+            let part: u8 = match flags.number("--part")? {
+                Some(part @ (1 | 2)) => part,
+                Some(part) => bail!("part must be 1 or 2, got {}", part),
+                None => bail!("pass --part <1|2>"),
+            };
+            let client = client(&flags)?;
+            let store = Store::new(".");
+
+            let answer = match flags.value("--answer") {
+                Some(answer) => answer.to_string(),
+                None => day.parts[part as usize - 1](&client.input(&store, number)?)?.answer,
+            };
+            let verdict = client.submit(&store, number, part, &answer)?;
+            println!("day {} part {}: {}: {}", number, part, answer, verdict);
+
+            if !verdict.is_correct() {
+                bail!("the answer wasn't accepted");
+            }
+            Ok(())
+        }
+        Some("serve") => {
+            let flags = Flags::parse(args, &[], &["--fixtures", "--port", "--cooldown"])?;
+            let fixtures = flags.value("--fixtures").unwrap_or(".");
+            let port = flags.number("--port")?.unwrap_or(DEFAULT_PORT);
+            let cooldown = flags.number("--cooldown")?.map_or(client::DEFAULT_COOLDOWN, Duration::from_secs);
+
+            let listener = TcpListener::bind(("127.0.0.1", port))
+                .with_context(|| format!("could not listen on port {}", port))?;
+            println!("serving inputs and answers from {} on http://{}", fixtures, listener.local_addr()?);
+            Server::new(fixtures, cooldown).serve(listener);
+            Ok(())
+        }
+        Some("new-day") => {
+            let flags = Flags::parse(args, &[], &["--day"])?;
+            let day: u8 = match flags.number("--day")? {
+                Some(day @ 1..=25) => day,
+                Some(day) => bail!("there's no day {} in the calendar", day),
+                None => bail!("pass --day <n>"),
+            };
+
+            let name = scaffold::new_day(Path::new("."), day)?;
+            println!("created {} and added it to the workspace and the runner", name);
+            println!("next, fetch its input with `aoc fetch --day {}` and paste the example into {}/src/example.txt", day, name);
+            Ok(())
+        }
+        _ => bail!("{}", USAGE),
+    }
+}
@@ -0,0 +1,165 @@
+//! Counting how much each phase allocates. With the `memory` feature on, the
+//! runner's global allocator keeps count for each thread of the bytes live
+//! and allocated and the number of allocations. Counts are per thread, so
+//! days solved side by side don't muddle each other's.
+
+use std::{cell::Cell, fmt::Display};
+#[cfg(feature = "memory")]
+use std::alloc::{GlobalAlloc, Layout, System};
+
+
+/// What one phase allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// The most memory live at once, over what was live when the phase started.
+    pub peak: usize,
+    /// Bytes allocated in all, counting memory freed along the way.
+    pub allocated: u64,
+    pub allocations: u64,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} peak, {} in {} allocs", bytes(self.peak as u64), bytes(self.allocated), self.allocations)
+    }
+}
+
+/// `512 B`, `1.5 KiB`, `12.3 MiB` and so on.
+fn bytes(n: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if n < 1024 {
+        return format!("{} B", n)
+    }
+
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+
+#[derive(Clone, Copy)]
+struct Counts {
+    live: usize,
+    peak: usize,
+    allocated: u64,
+    allocations: u64,
+}
+
+thread_local! {
+    // const initialised and without a destructor, so using it can't allocate
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts { live: 0, peak: 0, allocated: 0, allocations: 0 })
+    };
+}
+
+/// The system allocator, keeping count.
+#[cfg(feature = "memory")]
+pub struct Counting;
+
+#[cfg(feature = "memory")]
+impl Counting {
+    fn record(grown: usize, shrunk: usize) {
+        // fails while the thread is being torn down, when nobody's counting
+        let _ = COUNTS.try_with(|counts| {
+            let mut c = counts.get();
+            // memory freed on another thread than allocated it can take a
+            // thread's count below zero, which saturates
+            c.live = (c.live + grown).saturating_sub(shrunk);
+            c.peak = c.peak.max(c.live);
+            if grown > 0 {
+                c.allocated += grown as u64;
+                c.allocations += 1;
+            }
+            counts.set(c);
+        });
+    }
+}
+
+#[cfg(feature = "memory")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Counting::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Counting::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Counting::record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // counted as a fresh allocation of the new size
+            Counting::record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+
+/// Runs `f`, and says what it allocated if the `memory` feature is on.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    let before = COUNTS.with(|counts| {
+        let mut c = counts.get();
+        c.peak = c.live;
+        counts.set(c);
+        c
+    });
+    let out = f();
+    let after = COUNTS.with(Cell::get);
+
+    let usage = cfg!(feature = "memory").then(|| Usage {
+        peak: after.peak - before.live,
+        allocated: after.allocated - before.allocated,
+        allocations: after.allocations - before.allocations,
+    });
+    (out, usage)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_are_shown_in_binary_units() {
+        assert_eq!(bytes(512), "512 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(5 << 20), "5.0 MiB");
+    }
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn phases_count_their_own_allocations() {
+        let kept = vec![0_u8; 1000];
+        let ((), usage) = measure(|| {
+            let a = vec![0_u8; 4096];
+            drop(a);
+            let b = vec![0_u8; 1024];
+            drop(b);
+        });
+        drop(kept);
+
+        assert_eq!(usage, Some(Usage { peak: 4096, allocated: 5120, allocations: 2 }));
+    }
+}
@@ -0,0 +1,113 @@
+//! Running days side by side on a fixed pool of std threads.
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{atomic::{AtomicUsize, Ordering}, mpsc},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+
+
+/// Calls `f` on every item using up to `threads` worker threads, each taking
+/// the next item as soon as it's done with the last. The results come back in
+/// the order of `items`.
+pub fn map<T, R>(items: &[T], threads: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            let (sender, next, f) = (sender.clone(), &next, &f);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else { return };
+                // the receiver outlives the scope, so this can't fail
+                let _ = sender.send((index, f(item)));
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<(usize, R)> = receiver.into_iter().collect();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Runs `f`, turning a panic into an error so it only fails what `f` was
+/// working on.
+pub fn isolate<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| Err(anyhow!(
+        "panicked: {}",
+        panic_message(&*payload)
+    )))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message")
+}
+
+/// How much CPU time the calling thread has used, where the platform can
+/// say. Only 64-bit Linux for now, where `time_t` and `long` are both 64 bits
+/// and the thread clock is clock 3.
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+pub fn thread_cpu_time() -> Option<Duration> {
+    use std::os::raw::{c_int, c_long};
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: c_long,
+        tv_nsec: c_long,
+    }
+
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+
+    extern "C" {
+        fn clock_gettime(clock: c_int, time: *mut Timespec) -> c_int;
+    }
+
+    let mut time = Timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY: `time` is a valid timespec for clock_gettime to write to, and
+    // std already links the C library that defines it
+    let status = unsafe { clock_gettime(CLOCK_THREAD_CPUTIME_ID, &mut time) };
+    if status != 0 {
+        return None
+    }
+    Some(Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+#[cfg(not(all(target_os = "linux", target_pointer_width = "64")))]
+pub fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_keep_their_order_and_panics_stay_put() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = map(&items, 4, |&n| isolate(|| match n {
+            13 => panic!("unlucky"),
+            n => Ok(n * n),
+        }));
+
+        assert_eq!(results.len(), 20);
+        for (n, result) in results.iter().enumerate() {
+            match n {
+                13 => assert_eq!(result.as_ref().unwrap_err().to_string(), "panicked: unlucky"),
+                n => assert_eq!(*result.as_ref().unwrap(), (n * n) as u64),
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Starts a new day: writes an `aoc-N` crate from the templates in
+//! `aoc/templates/day` and registers it with the workspace and the runner.
+
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+
+/// The files of a new day's crate, relative to its directory, and the
+/// templates they're made from.
+const FILES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml.template")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs.template")),
+    ("src/main.rs", include_str!("../templates/day/main.rs.template")),
+    ("src/generate.rs", include_str!("../templates/day/generate.rs.template")),
+    ("src/example.txt", ""),
+];
+
+/// Creates day `day`'s crate under the workspace at `root` and adds it to the
+/// workspace members, the runner's dependencies and its list of days. Returns
+/// the crate's directory.
+pub fn new_day(root: &Path, day: u8) -> Result<String> {
+    let name = format!("aoc-{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    // work out every edit before writing anything, so a tree that doesn't
+    // look as expected is left alone
+    let edits = [
+        (root.join("Cargo.toml"), register_member as fn(&str, u8) -> Result<String>),
+        (root.join("aoc/Cargo.toml"), register_dependency),
+        (root.join("aoc/src/days.rs"), register_day),
+    ];
+    let edits = edits.into_iter()
+        .map(|(path, register)| {
+            let text = fs::read_to_string(&path)
+                .with_context(|| format!("could not read {}", path.display()))?;
+            let text = register(&text, day).with_context(|| format!("could not update {}", path.display()))?;
+            Ok((path, text))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    for (file, template) in FILES {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("could not create {}", parent.display()))?;
+        }
+        fs::write(&path, template.replace("{day}", &day.to_string()))
+            .with_context(|| format!("could not write {}", path.display()))?;
+    }
+    for (path, text) in edits {
+        fs::write(&path, text).with_context(|| format!("could not write {}", path.display()))?;
+    }
+
+    Ok(name)
+}
+
+
+/// Inserts `line` after the last line of `text` that starts with `after`,
+/// unless a line starting with `existing` is already there.
+fn insert_after_last(text: &str, after: &str, existing: &str, line: &str) -> Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.iter().any(|l| l.trim_start().starts_with(existing)) {
+        bail!("it already has {}", existing);
+    }
+    let last = lines.iter()
+        .rposition(|l| l.trim_start().starts_with(after))
+        .ok_or_else(|| anyhow!("couldn't find where to add {}", line.trim()))?;
+
+    let mut lines: Vec<String> = lines.into_iter().map(String::from).collect();
+    lines.insert(last + 1, line.to_string());
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_member(text: &str, day: u8) -> Result<String> {
+    let member = format!("\"aoc-{}\",", day);
+    insert_after_last(text, "\"aoc-", &member, &format!("    {}", member))
+}
+
+fn register_dependency(text: &str, day: u8) -> Result<String> {
+    let dependency = format!("aoc-{} = ", day);
+    insert_after_last(
+        text, "aoc-", &dependency,
+        &format!("{}{{ path = \"../aoc-{}\" }}", dependency, day),
+    )
+}
+
+fn register_day(text: &str, day: u8) -> Result<String> {
+    let entry = format!("day::<aoc_{}::Puzzle>", day);
+    insert_after_last(
+        text, "day::<aoc_", &entry,
+        &format!("    {}(aoc_{1}::generate::generate, aoc_{1}::generate::DEFAULT_SIZE),", entry, day),
+    )
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_after_the_last_one() {
+        let members = "members = [\n    \"aoc\",\n    \"aoc-common\",\n    \"aoc-12\",\n]\n";
+        assert_eq!(
+            register_member(members, 13).unwrap(),
+            "members = [\n    \"aoc\",\n    \"aoc-common\",\n    \"aoc-12\",\n    \"aoc-13\",\n]\n"
+        );
+
+        let dependencies = "aoc-common = { path = \"../aoc-common\" }\naoc-12 = { path = \"../aoc-12\" }\nserde_json = \"1.0\"\n";
+        assert_eq!(
+            register_dependency(dependencies, 13).unwrap(),
+            "aoc-common = { path = \"../aoc-common\" }\naoc-12 = { path = \"../aoc-12\" }\n\
+             aoc-13 = { path = \"../aoc-13\" }\nserde_json = \"1.0\"\n"
+        );
+
+        assert!(register_day("    day::<aoc_13::Puzzle>(aoc_13::generate::generate, 1),\n", 13).is_err());
+    }
+}
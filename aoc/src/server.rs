@@ -0,0 +1,125 @@
+//! A stand-in for the puzzle site, for using and testing the client offline.
+//! It serves inputs and judges answers from a fixture directory laid out like
+//! this repository (`inputs/day-NN.txt`, `answers/day-NN-part-P.txt`), and
+//! words its replies the way the real site does.
+
+use std::{
+    collections::HashSet,
+    fs,
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use aoc_common::input;
+
+use crate::{client::YEAR, http, verify};
+
+
+pub struct Server {
+    fixtures: PathBuf,
+    /// How long a wrong answer locks out further submissions.
+    cooldown: Duration,
+    locked_until: Option<Instant>,
+    solved: HashSet<(u8, u8)>,
+}
+
+impl Server {
+    pub fn new(fixtures: impl Into<PathBuf>, cooldown: Duration) -> Self {
+        Server { fixtures: fixtures.into(), cooldown, locked_until: None, solved: HashSet::new() }
+    }
+
+    /// Answers requests on `listener` one at a time, for as long as it's open.
+    pub fn serve(mut self, listener: TcpListener) {
+        for stream in listener.incoming().flatten() {
+            if let Err(err) = self.handle(&stream) {
+                eprintln!("serve: {:#}", err);
+            }
+        }
+    }
+
+    fn handle(&mut self, stream: &TcpStream) -> Result<()> {
+        let request = http::receive(stream)?;
+        let (status, body) = self.route(&request);
+        http::respond(stream, status, &body)
+    }
+
+    fn route(&mut self, request: &http::Request) -> (u16, String) {
+        if !request.header("Cookie").is_some_and(|cookie| cookie.contains("session=")) {
+            return (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".into())
+        }
+
+        let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+        let day = match segments[..] {
+            [year, "day", day, _] if year == YEAR.to_string() => day.parse::<u8>().ok(),
+            _ => None,
+        };
+        let Some(day) = day else { return (404, "404 Not Found".into()) };
+
+        match (request.method.as_str(), segments[3]) {
+            ("GET", "input") => match fs::read_to_string(self.fixtures.join(input::default_path(day))) {
+                Ok(input) => (200, input),
+                Err(_) => (404, "404 Not Found".into()),
+            },
+            ("POST", "answer") => {
+                let level = request.form_value("level").and_then(|l| l.parse().ok());
+                match (level, request.form_value("answer")) {
+                    (Some(part @ (1 | 2)), Some(answer)) => self.judge(day, part, answer.trim()),
+                    _ => (400, "Bad Request".into()),
+                }
+            }
+            _ => (404, "404 Not Found".into()),
+        }
+    }
+
+    fn judge(&mut self, day: u8, part: u8, answer: &str) -> (u16, String) {
+        let now = Instant::now();
+        if let Some(wait) = self.locked_until.and_then(|until| until.checked_duration_since(now)) {
+            return article(&format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {} left to wait.",
+                format_wait(wait)
+            ))
+        }
+
+        if self.solved.contains(&(day, part)) || (part == 2 && !self.solved.contains(&(day, 1))) {
+            return article("You don't seem to be solving the right level.  Did you already complete it?")
+        }
+
+        let expected = match fs::read_to_string(self.fixtures.join(verify::answer_path(day, part))) {
+            Ok(expected) => expected.trim().to_string(),
+            Err(_) => return (404, "404 Not Found".into()),
+        };
+
+        if answer == expected {
+            self.solved.insert((day, part));
+            return article("That's the right answer!  You are one gold star closer to saving your vacation.")
+        }
+
+        self.locked_until = Some(now + self.cooldown);
+        let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+            (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+            _ => "",
+        };
+        article(&format!(
+            "That's not the right answer{}.  If you're stuck, make sure you're using the full input data.  \
+             Please wait {} before trying again.",
+            hint, format_wait(self.cooldown)
+        ))
+    }
+}
+
+fn article(text: &str) -> (u16, String) {
+    (200, format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", text))
+}
+
+/// `1m 5s` or `42s`, like the site.
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    match secs / 60 {
+        0 => format!("{}s", secs),
+        minutes => format!("{}m {}s", minutes, secs % 60),
+    }
+}
@@ -0,0 +1,99 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+use anyhow::{Context, Result};
+
+use crate::days::Day;
+
+
+/// Directory holding one `day-NN-part-P.txt` file per accepted answer.
+pub const ANSWER_DIR: &str = "answers";
+
+pub fn answer_path(day: u8, part: u8) -> PathBuf {
+    Path::new(ANSWER_DIR).join(format!("day-{:02}-part-{}.txt", day, part))
+}
+
+/// The accepted answer for a part, or `None` if nothing has been recorded yet.
+pub fn expected(day: u8, part: u8) -> Result<Option<String>> {
+    let path = answer_path(day, part);
+    match fs::read_to_string(&path) {
+        Ok(answer) => Ok(Some(answer.trim().to_string())),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("could not read {}", path.display())),
+    }
+}
+
+pub fn record(day: u8, part: u8, answer: &str) -> Result<()> {
+    let path = answer_path(day, part);
+    fs::create_dir_all(ANSWER_DIR)
+        .with_context(|| format!("could not create {}", ANSWER_DIR))?;
+    fs::write(&path, format!("{}\n", answer))
+        .with_context(|| format!("could not write {}", path.display()))
+}
+
+
+pub enum Outcome {
+    /// Matches the recorded answer.
+    Correct,
+    /// Differs from the recorded answer.
+    Wrong { expected: String },
+    /// No answer was recorded for this part.
+    New,
+    /// The solver, or reading the answer file, failed.
+    Failed(anyhow::Error),
+}
+
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    /// Whether this check should make `verify` fail.
+    pub fn is_failure(&self) -> bool {
+        matches!(self.outcome, Outcome::Wrong { .. } | Outcome::Failed(_))
+    }
+}
+
+pub fn check(day: &Day, part: u8, input: &str) -> Check {
+    let result = day.parts[part as usize - 1](input)
+        .and_then(|timed| Ok((timed.answer, expected(day.number, part)?)));
+
+    let (answer, outcome) = match result {
+        Ok((answer, Some(expected))) if answer == expected => (Some(answer), Outcome::Correct),
+        Ok((answer, Some(expected))) => (Some(answer), Outcome::Wrong { expected }),
+        Ok((answer, None)) => (Some(answer), Outcome::New),
+        Err(err) => (None, Outcome::Failed(err)),
+    };
+
+    Check { day: day.number, part, answer, outcome }
+}
+
+
+pub fn print_table(checks: &[Check]) {
+    let answer_width = checks.iter()
+        .filter_map(|c| c.answer.as_ref())
+        .map(String::len)
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("{:>3}  {:>4}  {:<answer_width$}  status", "day", "part", "answer");
+    for check in checks {
+        let status = match &check.outcome {
+            Outcome::Correct => "ok".to_string(),
+            Outcome::Wrong { expected } => format!("WRONG, expected {}", expected),
+            Outcome::New => "new, no recorded answer".to_string(),
+            Outcome::Failed(err) => format!("error: {:#}", err),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {}",
+            check.day,
+            check.part,
+            check.answer.as_deref().unwrap_or("-"),
+            status
+        );
+    }
+}